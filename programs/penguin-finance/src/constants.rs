@@ -1,4 +1,4 @@
-/// Protocol-wide constants
+//! Protocol-wide constants
 
 /// Seed for factory PDA
 pub const FACTORY_SEED: &[u8] = b"factory";
@@ -15,9 +15,69 @@ pub const USER_POSITION_SEED: &[u8] = b"user_position";
 /// Seed for withdrawal ticket PDA
 pub const WITHDRAWAL_TICKET_SEED: &[u8] = b"withdrawal_ticket";
 
+/// Seed for pending parameter change PDA
+pub const PENDING_PARAM_CHANGE_SEED: &[u8] = b"pending_param_change";
+
+/// Seed for role registry PDA
+pub const ROLE_REGISTRY_SEED: &[u8] = b"role_registry";
+
+/// Seed for validator list PDA
+pub const VALIDATOR_LIST_SEED: &[u8] = b"validator_list";
+
 /// Seed for stake account PDA
 pub const STAKE_ACCOUNT_SEED: &[u8] = b"stake_account";
 
+/// Seed for transient stake account PDA (top-ups awaiting merge)
+pub const TRANSIENT_STAKE_SEED: &[u8] = b"transient_stake";
+
+/// Seed for per-epoch reward snapshot PDA
+pub const EPOCH_SNAPSHOT_SEED: &[u8] = b"epoch_snapshot";
+
+/// Seed for per-epoch oracle balance report PDA
+pub const BALANCE_REPORT_SEED: &[u8] = b"balance_report";
+
+/// Seed for per-vault exchange-rate history PDA
+pub const RATE_HISTORY_SEED: &[u8] = b"rate_history";
+
+/// Seed for per-vault liquidity pool PDA
+pub const LIQUIDITY_POOL_SEED: &[u8] = b"liquidity_pool";
+
+/// Seed for liquidity pool LP mint PDA
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+
+/// Seed for the system-owned PDA holding a vault's unstaked SOL
+pub const VAULT_RESERVE_SEED: &[u8] = b"vault_reserve";
+
+/// Pause flag for deposits and vault creation
+pub const PAUSE_DEPOSITS: u8 = 1 << 0;
+
+/// Pause flag for staking to validators
+pub const PAUSE_STAKING: u8 = 1 << 1;
+
+/// Pause flag for pSOL minting
+pub const PAUSE_MINTING: u8 = 1 << 2;
+
+/// Pause flag for pSOL burning
+pub const PAUSE_BURNING: u8 = 1 << 3;
+
+/// Pause flag for withdrawal requests
+pub const PAUSE_WITHDRAWAL_REQUESTS: u8 = 1 << 4;
+
+/// Pause flag for withdrawal claims
+pub const PAUSE_WITHDRAWAL_CLAIMS: u8 = 1 << 5;
+
+/// Pause flag for liquidations
+pub const PAUSE_LIQUIDATIONS: u8 = 1 << 6;
+
+/// All pause flags
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSITS
+    | PAUSE_STAKING
+    | PAUSE_MINTING
+    | PAUSE_BURNING
+    | PAUSE_WITHDRAWAL_REQUESTS
+    | PAUSE_WITHDRAWAL_CLAIMS
+    | PAUSE_LIQUIDATIONS;
+
 /// Minimum collateralization ratio (110%)
 pub const MIN_COLLATERAL_RATIO: u64 = 11000; // Basis points (110%)
 
@@ -27,18 +87,36 @@ pub const LIQUIDATION_THRESHOLD: u64 = 10500; // Basis points (105%)
 /// Liquidation bonus for liquidators (5%)
 pub const LIQUIDATION_BONUS: u64 = 500; // Basis points (5%)
 
+/// Maximum liquidation bonus (20%)
+pub const MAX_LIQUIDATION_BONUS: u64 = 2000; // Basis points (20%)
+
 /// Protocol fee on rewards (1%)
 pub const PROTOCOL_FEE_BPS: u16 = 100; // Basis points (1%)
 
+/// Maximum protocol fee (10%)
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1000; // Basis points (10%)
+
 /// Maximum operator fee (15%)
 pub const MAX_OPERATOR_FEE_BPS: u16 = 1500; // Basis points (15%)
 
+/// Notice period before an operator fee increase applies
+pub const FEE_INCREASE_NOTICE_EPOCHS: u64 = 2;
+
 /// Minimum stake amount (0.1 SOL)
 pub const MIN_STAKE_AMOUNT: u64 = 100_000_000; // lamports (0.1 SOL)
 
 /// Maximum vault name length
 pub const MAX_VAULT_NAME_LENGTH: usize = 32;
 
+/// Maximum number of keys holding roles
+pub const MAX_ROLE_MEMBERS: usize = 16;
+
+/// Maximum number of validators per vault
+pub const MAX_VALIDATORS: usize = 32;
+
+/// Balance updates kept in a vault's exchange-rate history
+pub const RATE_HISTORY_LEN: usize = 64;
+
 /// Basis points denominator
 pub const BASIS_POINTS_DIVISOR: u64 = 10000;
 
@@ -46,4 +124,38 @@ pub const BASIS_POINTS_DIVISOR: u64 = 10000;
 pub const MIN_RENT_EXEMPT: u64 = 1_000_000; // ~0.001 SOL
 
 /// Slots per epoch (approximate, for calculation purposes)
-pub const SLOTS_PER_EPOCH: u64 = 432_000;
\ No newline at end of file
+pub const SLOTS_PER_EPOCH: u64 = 432_000;
+
+/// Epochs per year (approximate, for APR bounds)
+pub const EPOCHS_PER_YEAR: u64 = 182;
+
+/// Default delay before queued parameter changes apply
+pub const DEFAULT_TIMELOCK_DELAY_EPOCHS: u64 = 2;
+
+/// Shortest timelock delay a parameter change may set
+pub const MIN_TIMELOCK_DELAY_EPOCHS: u64 = 1;
+
+/// Longest timelock delay a parameter change may set (~2 months)
+pub const MAX_TIMELOCK_DELAY_EPOCHS: u64 = 30;
+
+/// Slot-based equivalents of the timelock delay bounds
+pub const MIN_TIMELOCK_DELAY_SLOTS: u64 = MIN_TIMELOCK_DELAY_EPOCHS * SLOTS_PER_EPOCH;
+pub const MAX_TIMELOCK_DELAY_SLOTS: u64 = MAX_TIMELOCK_DELAY_EPOCHS * SLOTS_PER_EPOCH;
+
+/// Maximum oracle reports collected per vault per epoch
+pub const MAX_ORACLE_REPORTS: usize = 16;
+
+/// Default number of oracle reports needed to finalize a balance
+pub const DEFAULT_ORACLE_QUORUM: u8 = 3;
+
+/// Default allowed deviation of a report from the median
+pub const DEFAULT_ORACLE_TOLERANCE_BPS: u16 = 50; // 0.5%
+
+/// Default cap on the implied APR of a balance update
+pub const DEFAULT_MAX_REWARD_APR_BPS: u16 = 2000; // 20%
+
+/// Default cap on the exchange-rate move of a balance update
+pub const DEFAULT_MAX_RATE_JUMP_BPS: u16 = 100; // 1%
+
+/// Maximum instant unstake fee (10%)
+pub const MAX_POOL_FEE_BPS: u16 = 1000;
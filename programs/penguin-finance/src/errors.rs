@@ -76,4 +76,103 @@ pub enum ErrorCode {
 
     #[msg("No rewards available")]
     NoRewardsAvailable,
-}
\ No newline at end of file
+
+    #[msg("Protocol fee exceeds maximum allowed")]
+    ProtocolFeeTooHigh,
+
+    #[msg("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+
+    #[msg("Invalid protocol parameter value")]
+    InvalidParamValue,
+
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Signer lacks the required role")]
+    MissingRole,
+
+    #[msg("Role registry is full")]
+    RoleRegistryFull,
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Deposits are paused")]
+    DepositsPaused,
+
+    #[msg("Staking is paused")]
+    StakingPaused,
+
+    #[msg("pSOL minting is paused")]
+    MintingPaused,
+
+    #[msg("pSOL burning is paused")]
+    BurningPaused,
+
+    #[msg("Withdrawal requests are paused")]
+    WithdrawalRequestsPaused,
+
+    #[msg("Withdrawal claims are paused")]
+    WithdrawalClaimsPaused,
+
+    #[msg("Liquidations are paused")]
+    LiquidationsPaused,
+
+    #[msg("Signer is not the pending operator")]
+    InvalidPendingOperator,
+
+    #[msg("Operation not allowed in the current vault status")]
+    InvalidVaultStatus,
+
+    #[msg("Vault still holds staked SOL")]
+    VaultStillStaked,
+
+    #[msg("Stake amount below minimum delegation plus rent")]
+    StakeTooSmall,
+
+    #[msg("Transient stake account for this validator is in use")]
+    TransientStakeInUse,
+
+    #[msg("Validator list is full")]
+    ValidatorListFull,
+
+    #[msg("Validator not found in vault validator list")]
+    ValidatorNotFound,
+
+    #[msg("Invalid unstake amount")]
+    InvalidUnstakeAmount,
+
+    #[msg("Stake accounts do not match the vault validator list")]
+    StakeAccountsMismatch,
+
+    #[msg("Balance report already finalized for this epoch")]
+    BalanceReportFinalized,
+
+    #[msg("Reporter already submitted a report for this epoch")]
+    DuplicateReport,
+
+    #[msg("Balance report has not reached quorum")]
+    BalanceReportNotFinalized,
+
+    #[msg("No quarantined balance update to resolve")]
+    NoQuarantinedReport,
+
+    #[msg("Insufficient liquidity in the pool")]
+    InsufficientPoolLiquidity,
+
+    #[msg("Invalid liquidity pool fee curve")]
+    InvalidPoolFee,
+
+    #[msg("Output below the requested minimum")]
+    SlippageExceeded,
+
+    #[msg("Invalid liquidity amount")]
+    InvalidLiquidityAmount,
+
+    #[msg("A quarantined update is awaiting review")]
+    QuarantinePending,
+
+    #[msg("Vault reserve does not match the buffered SOL, sync it first")]
+    ReserveNotSynced,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{ProtocolParam, Role, VaultStatus};
+
 #[event]
 pub struct FactoryInitialized {
     pub factory: Pubkey,
@@ -105,4 +107,390 @@ pub struct VaultBalanceUpdated {
     pub new_total_assets: u64,
     pub rewards_earned: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct ProtocolPaused {
+    pub factory: Pubkey,
+    pub pauser: Pubkey,
+    pub flags: u8,
+    pub pause_flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolUnpaused {
+    pub factory: Pubkey,
+    pub authority: Pubkey,
+    pub flags: u8,
+    pub pause_flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultPaused {
+    pub vault: Pubkey,
+    pub pauser: Pubkey,
+    pub flags: u8,
+    pub pause_flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUnpaused {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub flags: u8,
+    pub pause_flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub factory: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub factory: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub factory: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParamChangeQueued {
+    pub factory: Pubkey,
+    pub change_id: u64,
+    pub param: ProtocolParam,
+    pub new_value: u64,
+    pub executable_at: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParamChangeExecuted {
+    pub factory: Pubkey,
+    pub change_id: u64,
+    pub param: ProtocolParam,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParamChangeCancelled {
+    pub factory: Pubkey,
+    pub change_id: u64,
+    pub param: ProtocolParam,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub factory: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub factory: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultStatusChanged {
+    pub vault: Pubkey,
+    pub old_status: VaultStatus,
+    pub new_status: VaultStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultCapacityUpdated {
+    pub vault: Pubkey,
+    pub old_max_capacity: u64,
+    pub new_max_capacity: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultRenamed {
+    pub vault: Pubkey,
+    pub vault_name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultFeeUpdated {
+    pub vault: Pubkey,
+    pub old_fee_basis_points: u16,
+    pub new_fee_basis_points: u16,
+    pub effective_epoch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorTransferProposed {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub pending_operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorTransferred {
+    pub vault: Pubkey,
+    pub old_operator: Pubkey,
+    pub new_operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeDeactivated {
+    pub vault: Pubkey,
+    pub validator: Pubkey,
+    pub stake_account: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeWithdrawn {
+    pub vault: Pubkey,
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WindDownWithdrawal {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_tokens_burned: u64,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub orphaned_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeMerged {
+    pub vault: Pubkey,
+    pub validator: Pubkey,
+    pub stake_account: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LossRealized {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub loss: u64,
+    pub total_assets: u64,
+    pub new_exchange_rate: u64,
+    pub high_water_mark: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BalanceReportSubmitted {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub reporter: Pubkey,
+    pub total_staked: u64,
+    pub report_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BalanceReportFinalized {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub median_total_staked: u64,
+    pub report_count: u8,
+    pub deviant_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleReportDeviated {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub reporter: Pubkey,
+    pub total_staked: u64,
+    pub median_total_staked: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub total_staked: u64,
+    pub reported_total_staked: u64,
+    pub max_reward_apr_bps: u16,
+    pub max_rate_jump_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QuarantineResolved {
+    pub vault: Pubkey,
+    pub reviewer: Pubkey,
+    pub approved: bool,
+    pub reported_total_staked: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalFulfilled {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub ticket_id: u64,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalCancelled {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub ticket_id: u64,
+    pub sol_amount: u64,
+    pub vault_tokens_minted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityPoolCreated {
+    pub vault: Pubkey,
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub min_fee_bps: u16,
+    pub max_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub sol_amount: u64,
+    pub lp_tokens_minted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub lp_tokens_burned: u64,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InstantUnstaked {
+    pub vault: Pubkey,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub ticket_id: u64,
+    pub vault_tokens_sold: u64,
+    pub sol_value: u64,
+    pub fee: u64,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolRedemptionClaimed {
+    pub vault: Pubkey,
+    pub pool: Pubkey,
+    pub ticket_id: u64,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalTicketTransferred {
+    pub vault: Pubkey,
+    pub ticket_id: u64,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeAccountWithdrawn {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub validator: Pubkey,
+    pub stake_account: Pubkey,
+    pub vault_tokens_burned: u64,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeDeposited {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub validator: Pubkey,
+    pub stake_account: Pubkey,
+    pub sol_amount: u64,
+    pub vault_tokens_minted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveSurplusBooked {
+    pub vault: Pubkey,
+    pub surplus: u64,
+    pub protocol_fee: u64,
+    pub operator_fee: u64,
+    pub staker_rewards: u64,
+    pub new_exchange_rate: u64, // Scaled by 1e9
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveSurplusQuarantined {
+    pub vault: Pubkey,
+    pub surplus: u64,
+    pub total_assets: u64,
+    pub max_reward_apr_bps: u16,
+    pub max_rate_jump_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveShortfallDetected {
+    pub vault: Pubkey,
+    pub reserve_balance: u64,
+    pub buffered_sol: u64,
+    pub shortfall: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+        constraint = factory.pending_authority == new_authority.key() @ ErrorCode::InvalidPendingAuthority,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let factory = &mut ctx.accounts.factory;
+    let clock = Clock::get()?;
+    let old_authority = factory.authority;
+
+    factory.authority = ctx.accounts.new_authority.key();
+    factory.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferred {
+        factory: factory.key(),
+        old_authority,
+        new_authority: factory.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Authority transferred: {} -> {}", old_authority, factory.authority);
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptOperator<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        constraint = vault.pending_operator == new_operator.key() @ ErrorCode::InvalidPendingOperator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub new_operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptOperator>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+    let old_operator = vault.operator;
+
+    vault.operator = ctx.accounts.new_operator.key();
+    vault.pending_operator = Pubkey::default();
+
+    emit!(OperatorTransferred {
+        vault: vault.key(),
+        old_operator,
+        new_operator: vault.operator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} operator transferred: {} -> {}", vault.vault_id, old_operator, vault.operator);
+
+    Ok(())
+}
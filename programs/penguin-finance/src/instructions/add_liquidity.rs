@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, liquidity_pool.vault.as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = lp_mint,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidLiquidityAmount);
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let clock = Clock::get()?;
+
+    let lp_tokens = liquidity_pool.calculate_lp_tokens(amount, ctx.accounts.lp_mint.supply)?;
+
+    // Transfer SOL from provider to pool
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.provider.to_account_info(),
+            to: liquidity_pool.to_account_info(),
+        },
+    );
+    transfer(transfer_ctx, amount)?;
+
+    liquidity_pool.sol_reserve = liquidity_pool
+        .sol_reserve
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    // Mint LP tokens to provider
+    let pool_seeds = &[
+        LIQUIDITY_POOL_SEED,
+        liquidity_pool.vault.as_ref(),
+        &[liquidity_pool.bump],
+    ];
+    let signer_seeds = &[&pool_seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            to: ctx.accounts.provider_lp_account.to_account_info(),
+            authority: liquidity_pool.to_account_info(),
+        },
+        signer_seeds,
+    );
+    mint_to(mint_ctx, lp_tokens)?;
+
+    emit!(LiquidityAdded {
+        pool: liquidity_pool.key(),
+        provider: ctx.accounts.provider.key(),
+        sol_amount: amount,
+        lp_tokens_minted: lp_tokens,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Liquidity added by {}", ctx.accounts.provider.key());
+    msg!("SOL amount: {}", amount as f64 / 1e9);
+    msg!("LP tokens minted: {}", lp_tokens as f64 / 1e9);
+
+    Ok(())
+}
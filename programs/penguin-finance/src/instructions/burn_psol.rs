@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{burn, Burn, Token};
+use anchor_spl::token_2022::{transfer_checked, Token2022, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
@@ -30,7 +32,11 @@ pub struct BurnPsol<'info> {
     pub psol_controller: Account<'info, PsolController>,
 
     #[account(mut)]
-    pub psol_mint: Account<'info, Mint>,
+    pub psol_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault token mint for this vault
+    #[account(address = vault.vault_token_mint)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
@@ -49,18 +55,20 @@ pub struct BurnPsol<'info> {
     /// User's vault token account (receives collateral back)
     #[account(
         mut,
-        associated_token::mint = vault.vault_token_mint,
+        associated_token::mint = vault_token_mint,
         associated_token::authority = user,
+        associated_token::token_program = vault_token_program,
     )]
-    pub user_vault_token_account: Account<'info, TokenAccount>,
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Position's vault token account (holds collateral)
     #[account(
         mut,
-        associated_token::mint = vault.vault_token_mint,
+        associated_token::mint = vault_token_mint,
         associated_token::authority = user_position,
+        associated_token::token_program = vault_token_program,
     )]
-    pub position_vault_token_account: Account<'info, TokenAccount>,
+    pub position_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// User's pSOL token account (source of pSOL to burn)
     #[account(
@@ -68,16 +76,19 @@ pub struct BurnPsol<'info> {
         associated_token::mint = psol_mint,
         associated_token::authority = user,
     )]
-    pub user_psol_account: Account<'info, TokenAccount>,
+    pub user_psol_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub vault_token_program: Program<'info, Token2022>,
 }
 
 pub fn handler(ctx: Context<BurnPsol>, psol_amount: u64) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_BURNING)?;
     require!(psol_amount > 0, ErrorCode::InvalidPsolAmount);
 
     let vault = &ctx.accounts.vault;
@@ -124,15 +135,16 @@ pub fn handler(ctx: Context<BurnPsol>, psol_amount: u64) -> Result<()> {
     let signer_seeds = &[&position_seeds[..]];
 
     let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        ctx.accounts.vault_token_program.to_account_info(),
+        TransferChecked {
             from: ctx.accounts.position_vault_token_account.to_account_info(),
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
             to: ctx.accounts.user_vault_token_account.to_account_info(),
             authority: user_position.to_account_info(),
         },
         signer_seeds,
     );
-    transfer(transfer_ctx, collateral_to_release)?;
+    transfer_checked(transfer_ctx, collateral_to_release, ctx.accounts.vault_token_mint.decimals)?;
 
     // Calculate collateral value released
     let exchange_rate = vault.exchange_rate()?;
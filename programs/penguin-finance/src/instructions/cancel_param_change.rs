@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelParamChange<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        mut,
+        seeds = [
+            PENDING_PARAM_CHANGE_SEED,
+            factory.key().as_ref(),
+            &pending_change.change_id.to_le_bytes()
+        ],
+        bump = pending_change.bump,
+        has_one = factory,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_change: Account<'info, PendingParamChange>,
+
+    /// Authority, or a holder of the role that may queue this parameter
+    pub canceller: Signer<'info>,
+
+    /// CHECK: Receives the rent back, checked against `pending_change.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelParamChange>) -> Result<()> {
+    let pending_change = &ctx.accounts.pending_change;
+    let canceller = ctx.accounts.canceller.key();
+    let clock = Clock::get()?;
+
+    // The authority can veto any queued change
+    if canceller != ctx.accounts.factory.authority {
+        pending_change.param.require_manager(
+            &canceller,
+            &ctx.accounts.factory,
+            &ctx.accounts.role_registry,
+        )?;
+    }
+
+    emit!(ParamChangeCancelled {
+        factory: ctx.accounts.factory.key(),
+        change_id: pending_change.change_id,
+        param: pending_change.param,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Cancelled change {} of {:?}", pending_change.change_id, pending_change.param);
+
+    Ok(())
+}
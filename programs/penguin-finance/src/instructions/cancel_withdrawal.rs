@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{mint_to, MintTo, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = vault_token_mint,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            WITHDRAWAL_TICKET_SEED,
+            vault.key().as_ref(),
+            &withdrawal_ticket.ticket_id.to_le_bytes()
+        ],
+        bump = withdrawal_ticket.bump,
+        has_one = vault,
+        constraint = withdrawal_ticket.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+
+    /// User's vault token account (receives the re-minted shares)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+}
+
+/// Shares are minted at the current exchange rate, so the user carries
+/// any rewards or losses booked while the ticket was queued
+pub fn handler(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_REQUESTS)?;
+    require!(
+        ctx.accounts.vault.status != VaultStatus::Closed,
+        ErrorCode::InvalidVaultStatus
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    let withdrawal_ticket = &ctx.accounts.withdrawal_ticket;
+    let clock = Clock::get()?;
+    let sol_amount = withdrawal_ticket.expected_sol_amount;
+
+    // Give back the ticket's place in the queue or its reserved buffer
+    if withdrawal_ticket.ready_to_claim {
+        vault.reserved_sol = vault
+            .reserved_sol
+            .checked_sub(sol_amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    } else {
+        vault.pending_withdrawal_sol = vault
+            .pending_withdrawal_sol
+            .checked_sub(sol_amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    }
+
+    let shares_to_mint = vault.calculate_shares(sol_amount)?;
+
+    vault.total_assets = vault
+        .total_assets
+        .checked_add(sol_amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    vault.total_shares = vault
+        .total_shares
+        .checked_add(shares_to_mint)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    // Mint vault tokens back to user
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
+            to: ctx.accounts.user_vault_token_account.to_account_info(),
+            authority: vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    mint_to(mint_ctx, shares_to_mint)?;
+
+    emit!(WithdrawalCancelled {
+        vault: vault.key(),
+        user: ctx.accounts.user.key(),
+        ticket_id: withdrawal_ticket.ticket_id,
+        sol_amount,
+        vault_tokens_minted: shares_to_mint,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Withdrawal ticket {} cancelled by {}", withdrawal_ticket.ticket_id, ctx.accounts.user.key());
+    msg!("SOL amount: {}", sol_amount as f64 / 1e9);
+    msg!("Vault tokens minted: {}", shares_to_mint as f64 / 1e9);
+
+    Ok(())
+}
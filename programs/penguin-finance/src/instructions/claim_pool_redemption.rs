@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::transfer_from_reserve;
+
+#[derive(Accounts)]
+pub struct ClaimPoolRedemption<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, vault.key().as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = vault,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        mut,
+        close = liquidity_pool,
+        seeds = [
+            WITHDRAWAL_TICKET_SEED,
+            vault.key().as_ref(),
+            &withdrawal_ticket.ticket_id.to_le_bytes()
+        ],
+        bump = withdrawal_ticket.bump,
+        has_one = vault,
+        constraint = withdrawal_ticket.owner == liquidity_pool.key() @ ErrorCode::InvalidWithdrawalTicket,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+
+    /// Anyone can settle the pool's fulfilled tickets
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The ticket rent goes back to the pool, which funded it on `instant_unstake`
+pub fn handler(ctx: Context<ClaimPoolRedemption>) -> Result<()> {
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_CLAIMS)?;
+
+    let vault = &mut ctx.accounts.vault;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let withdrawal_ticket = &ctx.accounts.withdrawal_ticket;
+    let clock = Clock::get()?;
+
+    require!(withdrawal_ticket.ready_to_claim, ErrorCode::WithdrawalNotReady);
+
+    let sol_amount = withdrawal_ticket.expected_sol_amount;
+    let ticket_rent = withdrawal_ticket.to_account_info().lamports();
+
+    vault.release_reserved(sol_amount)?;
+
+    transfer_from_reserve(
+        vault,
+        &ctx.accounts.vault_reserve.to_account_info(),
+        &liquidity_pool.to_account_info(),
+        &ctx.accounts.system_program,
+        sol_amount,
+    )?;
+
+    liquidity_pool.pending_redemption_sol = liquidity_pool
+        .pending_redemption_sol
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    liquidity_pool.sol_reserve = liquidity_pool
+        .sol_reserve
+        .checked_add(sol_amount)
+        .and_then(|v| v.checked_add(ticket_rent))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(PoolRedemptionClaimed {
+        vault: vault.key(),
+        pool: liquidity_pool.key(),
+        ticket_id: withdrawal_ticket.ticket_id,
+        sol_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pool redeemed ticket {} for {} SOL",
+        withdrawal_ticket.ticket_id,
+        sol_amount as f64 / 1e9
+    );
+
+    Ok(())
+}
@@ -4,6 +4,7 @@ use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::*;
 use crate::state::*;
+use crate::utils::transfer_from_reserve;
 
 #[derive(Accounts)]
 pub struct ClaimWithdrawal<'info> {
@@ -22,20 +23,31 @@ pub struct ClaimWithdrawal<'info> {
 
     #[account(
         mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
         seeds = [
             WITHDRAWAL_TICKET_SEED,
-            withdrawal_ticket.vault.as_ref(),
-            user.key().as_ref(),
+            vault.key().as_ref(),
             &withdrawal_ticket.ticket_id.to_le_bytes()
         ],
         bump = withdrawal_ticket.bump,
-        constraint = withdrawal_ticket.user == user.key() @ ErrorCode::Unauthorized,
-        constraint = !withdrawal_ticket.claimed @ ErrorCode::InvalidWithdrawalTicket,
+        has_one = vault,
+        constraint = withdrawal_ticket.owner == user.key() @ ErrorCode::Unauthorized,
     )]
     pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
 
-    /// CHECK: User receiving SOL
-    #[account(mut)]
+    /// CHECK: Ticket owner receiving SOL and the ticket rent. Tickets held by a
+    /// liquidity pool settle through `claim_pool_redemption`
+    #[account(
+        mut,
+        constraint = *user.owner != crate::ID @ ErrorCode::InvalidWithdrawalTicket,
+    )]
     pub user: UncheckedAccount<'info>,
 
     #[account(mut)]
@@ -45,53 +57,31 @@ pub struct ClaimWithdrawal<'info> {
 }
 
 pub fn handler(ctx: Context<ClaimWithdrawal>) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_CLAIMS)?;
 
     let vault = &mut ctx.accounts.vault;
-    let withdrawal_ticket = &mut ctx.accounts.withdrawal_ticket;
+    let withdrawal_ticket = &ctx.accounts.withdrawal_ticket;
     let clock = Clock::get()?;
 
-    // Check if withdrawal is ready
-    let is_ready = vault.buffered_sol >= withdrawal_ticket.expected_sol_amount;
-    
-    require!(is_ready, ErrorCode::WithdrawalNotReady);
+    // Buffer was reserved for the ticket when the queue reached it
+    require!(withdrawal_ticket.ready_to_claim, ErrorCode::WithdrawalNotReady);
 
     let sol_amount = withdrawal_ticket.expected_sol_amount;
 
-    // Transfer SOL from vault to user
-    let vault_seeds = &[
-        VAULT_SEED,
-        vault.factory.as_ref(),
-        &vault.vault_id.to_le_bytes(),
-        &[vault.bump],
-    ];
-    let signer_seeds = &[&vault_seeds[..]];
-
-    // Use invoke_signed to transfer SOL
-    anchor_lang::solana_program::program::invoke_signed(
-        &anchor_lang::solana_program::system_instruction::transfer(
-            &vault.key(),
-            &ctx.accounts.user.key(),
-            sol_amount,
-        ),
-        &[
-            vault.to_account_info(),
-            ctx.accounts.user.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        signer_seeds,
-    )?;
-
     // Update vault state
-    vault.buffered_sol = vault
-        .buffered_sol
-        .checked_sub(sol_amount)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    vault.release_reserved(sol_amount)?;
 
-    // Mark ticket as claimed
-    withdrawal_ticket.claimed = true;
-    withdrawal_ticket.ready_to_claim = true;
+    transfer_from_reserve(
+        vault,
+        &ctx.accounts.vault_reserve.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program,
+        sol_amount,
+    )?;
 
+    // The ticket is closed on exit and its rent returned to the user
     emit!(WithdrawalCompleted {
         vault: vault.key(),
         user: ctx.accounts.user.key(),
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::transfer_from_reserve;
+
+#[derive(Accounts)]
+pub struct ClaimWithdrawals<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    /// CHECK: Ticket owner receiving SOL and the ticket rent. Tickets held by a
+    /// liquidity pool settle through `claim_pool_redemption`
+    #[account(
+        mut,
+        constraint = *user.owner != crate::ID @ ErrorCode::InvalidWithdrawalTicket,
+    )]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: fulfilled withdrawal tickets of this vault owned by `user`
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimWithdrawals<'info>>) -> Result<()> {
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_CLAIMS)?;
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        ErrorCode::InvalidWithdrawalTicket
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    let vault_key = vault.key();
+    let user = ctx.accounts.user.to_account_info();
+    let clock = Clock::get()?;
+    let mut total_sol: u64 = 0;
+
+    for ticket_info in ctx.remaining_accounts.iter() {
+        let ticket = Account::<WithdrawalTicket>::try_from(ticket_info)?;
+
+        let expected_key = Pubkey::create_program_address(
+            &[
+                WITHDRAWAL_TICKET_SEED,
+                vault_key.as_ref(),
+                &ticket.ticket_id.to_le_bytes(),
+                &[ticket.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| error!(ErrorCode::InvalidWithdrawalTicket))?;
+        require_keys_eq!(
+            ticket_info.key(),
+            expected_key,
+            ErrorCode::InvalidWithdrawalTicket
+        );
+        require_keys_eq!(ticket.vault, vault_key, ErrorCode::InvalidWithdrawalTicket);
+        require_keys_eq!(ticket.owner, user.key(), ErrorCode::Unauthorized);
+        require!(ticket.ready_to_claim, ErrorCode::WithdrawalNotReady);
+
+        let sol_amount = ticket.expected_sol_amount;
+        vault.release_reserved(sol_amount)?;
+        total_sol = total_sol
+            .checked_add(sol_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        emit!(WithdrawalCompleted {
+            vault: vault_key,
+            user: user.key(),
+            ticket_id: ticket.ticket_id,
+            sol_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        ticket.close(user.clone())?;
+    }
+
+    transfer_from_reserve(
+        vault,
+        &ctx.accounts.vault_reserve.to_account_info(),
+        &user,
+        &ctx.accounts.system_program,
+        total_sol,
+    )?;
+
+    msg!("{} withdrawal tickets claimed by {}", ctx.remaining_accounts.len(), user.key());
+    msg!("SOL amount: {}", total_sol as f64 / 1e9);
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{close_account, CloseAccount, Token2022};
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::transfer_from_reserve;
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = operator,
+        has_one = vault_token_mint,
+        close = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Closed through its `MintCloseAuthority`, held by the vault
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+        close = operator,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    #[account(
+        mut,
+        seeds = [RATE_HISTORY_SEED, vault.key().as_ref()],
+        bump = rate_history.load()?.bump,
+        close = operator,
+    )]
+    pub rate_history: AccountLoader<'info, ExchangeRateHistory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    /// CHECK: Protocol treasury, checked against `factory.treasury`
+    #[account(mut, address = factory.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Close a fully redeemed vault with its token mint, reserve, validator list
+/// and rate history, returning all of their rent to the operator. SOL booked
+/// into the buffer after the last holder left has no owner and goes to the
+/// treasury.
+pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    require!(
+        vault.status == VaultStatus::WindingDown,
+        ErrorCode::InvalidVaultStatus
+    );
+    require!(vault.total_shares == 0, ErrorCode::InvalidVaultState);
+    require!(vault.total_staked == 0, ErrorCode::VaultStillStaked);
+    // Every withdrawal ticket must be claimed first
+    require!(
+        vault.pending_withdrawal_sol == 0 && vault.reserved_sol == 0,
+        ErrorCode::InvalidVaultState
+    );
+    require!(!vault.quarantined, ErrorCode::QuarantinePending);
+
+    // Unbooked lamports are settled by `sync_vault_reserve`, never swept here
+    let rent = Rent::get()?.minimum_balance(0);
+    let expected_reserve = rent
+        .checked_add(vault.buffered_sol)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(
+        ctx.accounts.vault_reserve.lamports() == expected_reserve,
+        ErrorCode::ReserveNotSynced
+    );
+
+    let orphaned_sol = vault.buffered_sol;
+    if orphaned_sol > 0 {
+        transfer_from_reserve(
+            vault,
+            &ctx.accounts.vault_reserve.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.system_program,
+            orphaned_sol,
+        )?;
+    }
+
+    transfer_from_reserve(
+        vault,
+        &ctx.accounts.vault_reserve.to_account_info(),
+        &ctx.accounts.operator.to_account_info(),
+        &ctx.accounts.system_program,
+        rent,
+    )?;
+
+    // The token program only closes a mint with zero supply
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault_token_mint.to_account_info(),
+            destination: ctx.accounts.operator.to_account_info(),
+            authority: vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    close_account(close_ctx)?;
+
+    let old_status = vault.status;
+    vault.status = VaultStatus::Closed;
+
+    emit!(VaultStatusChanged {
+        vault: vault.key(),
+        old_status,
+        new_status: vault.status,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(VaultClosed {
+        vault: vault.key(),
+        operator: ctx.accounts.operator.key(),
+        orphaned_sol,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} closed by {}", vault.vault_id, ctx.accounts.operator.key());
+    if orphaned_sol > 0 {
+        msg!("Orphaned buffer sent to treasury: {} SOL", orphaned_sol as f64 / 1e9);
+    }
+
+    Ok(())
+}
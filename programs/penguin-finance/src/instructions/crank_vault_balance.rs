@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::stake;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::{mint_fee_shares, read_stake_delegation};
+
+#[derive(Accounts)]
+pub struct CrankVaultBalance<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = vault_token_mint,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// Reward snapshot for this epoch, its PDA allows one update per epoch
+    #[account(
+        init,
+        payer = cranker,
+        space = EpochSnapshot::LEN,
+        seeds = [EPOCH_SNAPSHOT_SEED, vault.key().as_ref(), &clock.epoch.to_le_bytes()],
+        bump,
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+
+    #[account(
+        mut,
+        seeds = [RATE_HISTORY_SEED, vault.key().as_ref()],
+        bump = rate_history.load()?.bump,
+    )]
+    pub rate_history: AccountLoader<'info, ExchangeRateHistory>,
+
+    /// CHECK: Vault operator, checked against `vault.operator`
+    #[account(address = vault.operator)]
+    pub operator: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol treasury, checked against `factory.treasury`
+    #[account(address = factory.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Operator's vault token account (receives fee shares)
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = operator,
+        associated_token::token_program = token_program,
+    )]
+    pub operator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury's vault token account (receives protocol fee shares)
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Anyone can crank, the balance is read from the stake accounts
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+}
+
+/// Remaining accounts: `[stake_account, transient_stake_account]` for every
+/// validator list entry that is not `Removed`, in list order
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CrankVaultBalance<'info>>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let clock = Clock::get()?;
+
+    // Entries are only rewritten once the breaker accepts the new balance, so
+    // they keep matching `vault.total_staked` while an update is quarantined
+    let (new_total_staked, balances) = {
+        let validator_list = ctx.accounts.validator_list.load()?;
+        let count = validator_list.count as usize;
+        let mut stake_accounts = ctx.remaining_accounts.chunks_exact(2);
+        let mut new_total_staked: u64 = 0;
+        let mut balances = Vec::with_capacity(count);
+
+        for (index, entry) in validator_list.validators[..count].iter().enumerate() {
+            if entry.status() == ValidatorStatus::Removed {
+                continue;
+            }
+
+            let pair = stake_accounts
+                .next()
+                .ok_or(ErrorCode::StakeAccountsMismatch)?;
+            let (stake_account, transient_stake_account) = (&pair[0], &pair[1]);
+
+            require_keys_eq!(
+                stake_account.key(),
+                entry.stake_account,
+                ErrorCode::StakeAccountsMismatch
+            );
+            let (transient_key, _) = Pubkey::find_program_address(
+                &[
+                    TRANSIENT_STAKE_SEED,
+                    vault_key.as_ref(),
+                    entry.vote_account.as_ref(),
+                ],
+                ctx.program_id,
+            );
+            require_keys_eq!(
+                transient_stake_account.key(),
+                transient_key,
+                ErrorCode::StakeAccountsMismatch
+            );
+
+            // The main account must still be delegated to the listed validator
+            require_keys_eq!(
+                read_stake_delegation(stake_account)?.voter_pubkey,
+                entry.vote_account,
+                ErrorCode::InvalidStakeState
+            );
+            let active_lamports = stake_account.lamports();
+
+            // An unused transient PDA is not a stake account and holds nothing of ours
+            let transient_lamports = if *transient_stake_account.owner == stake::program::ID {
+                read_stake_delegation(transient_stake_account)?;
+                transient_stake_account.lamports()
+            } else {
+                0
+            };
+
+            new_total_staked = new_total_staked
+                .checked_add(active_lamports)
+                .and_then(|v| v.checked_add(transient_lamports))
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            balances.push((index, active_lamports, transient_lamports));
+        }
+
+        // Every stake account must be accounted for, none left over
+        require!(
+            stake_accounts.next().is_none() && stake_accounts.remainder().is_empty(),
+            ErrorCode::StakeAccountsMismatch
+        );
+
+        (new_total_staked, balances)
+    };
+
+    let vault = &mut ctx.accounts.vault;
+
+    let epoch_snapshot = &mut ctx.accounts.epoch_snapshot;
+    epoch_snapshot.record_start(vault, clock.epoch, ctx.bumps.epoch_snapshot)?;
+
+    let factory = &ctx.accounts.factory;
+    if !vault.within_reward_bounds(
+        new_total_staked,
+        clock.epoch,
+        factory.max_reward_apr_bps,
+        factory.max_rate_jump_bps,
+    )? {
+        vault.quarantine(new_total_staked, clock.epoch);
+        epoch_snapshot.record_distribution(&RewardDistribution::default(), vault.exchange_rate()?);
+
+        emit!(CircuitBreakerTripped {
+            vault: vault.key(),
+            epoch: clock.epoch,
+            total_staked: vault.total_staked,
+            reported_total_staked: new_total_staked,
+            max_reward_apr_bps: factory.max_reward_apr_bps,
+            max_rate_jump_bps: factory.max_rate_jump_bps,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Circuit breaker tripped for vault {}: {} SOL reported against {} SOL staked",
+            vault.vault_id,
+            new_total_staked as f64 / 1e9,
+            vault.total_staked as f64 / 1e9
+        );
+        msg!("Balance update quarantined and minting paused pending review");
+        return Ok(());
+    }
+
+    let distribution = vault.distribute_rewards(
+        new_total_staked,
+        factory.protocol_fee_bps,
+        clock.epoch,
+    )?;
+
+    let mut validator_list = ctx.accounts.validator_list.load_mut()?;
+    for (index, active_lamports, transient_lamports) in balances {
+        let entry = &mut validator_list.validators[index];
+        entry.active_lamports = active_lamports;
+        entry.transient_lamports = transient_lamports;
+        entry.last_update_epoch = clock.epoch;
+    }
+    drop(validator_list);
+
+    epoch_snapshot.record_distribution(&distribution, vault.exchange_rate()?);
+
+    ctx.accounts.rate_history.load_mut()?.push(RateHistoryEntry {
+        epoch: clock.epoch,
+        total_assets: vault.total_assets,
+        total_shares: vault.total_shares,
+        exchange_rate: epoch_snapshot.exchange_rate,
+        rewards: distribution.rewards,
+    });
+
+    if distribution.loss > 0 {
+        let new_exchange_rate = vault.exchange_rate()?;
+
+        emit!(LossRealized {
+            vault: vault.key(),
+            epoch: clock.epoch,
+            loss: distribution.loss,
+            total_assets: vault.total_assets,
+            new_exchange_rate,
+            high_water_mark: vault.high_water_mark,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Loss realized for vault {}", vault.vault_id);
+        msg!("Loss: {} SOL", distribution.loss as f64 / 1e9);
+        msg!("New exchange rate: {}", new_exchange_rate as f64 / 1e9);
+        return Ok(());
+    }
+
+    if distribution.rewards == 0 {
+        msg!("No rewards to distribute");
+        return Ok(());
+    }
+
+    mint_fee_shares(
+        vault,
+        &ctx.accounts.vault_token_mint,
+        &ctx.accounts.operator_token_account,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.token_program,
+        &distribution,
+    )?;
+
+    let new_exchange_rate = vault.exchange_rate()?;
+
+    emit!(RewardsDistributed {
+        vault: vault.key(),
+        epoch: clock.epoch,
+        total_rewards: distribution.rewards,
+        protocol_fee: distribution.protocol_fee,
+        operator_fee: distribution.operator_fee,
+        staker_rewards: distribution.staker_rewards,
+        new_exchange_rate,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Rewards distributed for vault {} from on-chain stake balances", vault.vault_id);
+    msg!("Total staked: {} SOL", new_total_staked as f64 / 1e9);
+    msg!("Total rewards: {} SOL", distribution.rewards as f64 / 1e9);
+    msg!("Protocol fee: {} SOL", distribution.protocol_fee as f64 / 1e9);
+    msg!("Operator fee: {} SOL", distribution.operator_fee as f64 / 1e9);
+    msg!("Staker rewards: {} SOL", distribution.staker_rewards as f64 / 1e9);
+    msg!("New exchange rate: {}", new_exchange_rate as f64 / 1e9);
+
+    Ok(())
+}
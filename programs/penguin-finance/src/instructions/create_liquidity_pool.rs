@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateLiquidityPool<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = LiquidityPool::LEN,
+        seeds = [LIQUIDITY_POOL_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        init,
+        payer = operator,
+        mint::decimals = 9,
+        mint::authority = liquidity_pool,
+        seeds = [LP_MINT_SEED, liquidity_pool.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<CreateLiquidityPool>, min_fee_bps: u16, max_fee_bps: u16) -> Result<()> {
+    require!(
+        min_fee_bps <= max_fee_bps && max_fee_bps <= MAX_POOL_FEE_BPS,
+        ErrorCode::InvalidPoolFee
+    );
+
+    let vault = &ctx.accounts.vault;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let clock = Clock::get()?;
+
+    liquidity_pool.vault = vault.key();
+    liquidity_pool.lp_mint = ctx.accounts.lp_mint.key();
+    liquidity_pool.sol_reserve = 0;
+    liquidity_pool.pending_redemption_sol = 0;
+    liquidity_pool.min_fee_bps = min_fee_bps;
+    liquidity_pool.max_fee_bps = max_fee_bps;
+    liquidity_pool.lifetime_fees = 0;
+    liquidity_pool.bump = ctx.bumps.liquidity_pool;
+
+    emit!(LiquidityPoolCreated {
+        vault: vault.key(),
+        pool: liquidity_pool.key(),
+        lp_mint: liquidity_pool.lp_mint,
+        min_fee_bps,
+        max_fee_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Liquidity pool created for vault {}", vault.vault_id);
+    msg!("Fee: {} - {} bps", min_fee_bps, max_fee_bps);
+
+    Ok(())
+}
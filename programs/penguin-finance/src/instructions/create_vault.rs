@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::Mint;
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
@@ -30,16 +32,44 @@ pub struct CreateVault<'info> {
         payer = operator,
         mint::decimals = 9,
         mint::authority = vault,
+        mint::token_program = token_program,
+        extensions::close_authority::authority = vault,
         seeds = [b"vault_token_mint", vault.key().as_ref()],
         bump
     )]
-    pub vault_token_mint: Account<'info, Mint>,
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = ValidatorList::LEN,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = ExchangeRateHistory::LEN,
+        seeds = [RATE_HISTORY_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub rate_history: AccountLoader<'info, ExchangeRateHistory>,
+
+    /// Data-less reserve holding the vault's unstaked SOL, funded with rent here
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub vault_reserve: SystemAccount<'info>,
 
     #[account(mut)]
     pub operator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Program<'info, Token2022>,
     pub rent: Sysvar<'info, Rent>,
 }
 
@@ -49,7 +79,7 @@ pub fn handler(
     max_capacity: u64,
     vault_name: String,
 ) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
+    ctx.accounts.factory.require_not_paused(0, PAUSE_DEPOSITS)?;
     require!(
         fee_basis_points <= MAX_OPERATOR_FEE_BPS,
         ErrorCode::OperatorFeeTooHigh
@@ -68,20 +98,64 @@ pub fn handler(
     vault.factory = factory.key();
     vault.vault_id = vault_id;
     vault.operator = ctx.accounts.operator.key();
+    vault.pending_operator = Pubkey::default();
     vault.vault_token_mint = ctx.accounts.vault_token_mint.key();
     vault.fee_basis_points = fee_basis_points;
+    vault.pending_fee_basis_points = 0;
+    vault.pending_fee_epoch = 0;
     vault.max_capacity = max_capacity;
     vault.total_staked = 0;
     vault.buffered_sol = 0;
+    vault.pending_withdrawal_sol = 0;
+    vault.reserved_sol = 0;
     vault.total_shares = 0;
     vault.total_assets = 0;
     vault.last_reward_epoch = clock.epoch;
-    vault.accepting_deposits = true;
+    vault.high_water_mark = 1_000_000_000;
+    vault.status = VaultStatus::Active;
+    vault.pause_flags = 0;
     vault.vault_name = vault_name.clone();
     vault.active_validators = 0;
     vault.lifetime_rewards = 0;
+    vault.quarantined = false;
+    vault.quarantined_total_staked = 0;
+    vault.quarantined_epoch = 0;
+    vault.breaker_override = false;
+    vault.next_ticket_id = 0;
+    vault.next_fulfill_ticket_id = 0;
+    vault.reserve_bump = ctx.bumps.vault_reserve;
     vault.bump = ctx.bumps.vault;
 
+    // Make the reserve rent exempt so `buffered_sol` is its balance above rent
+    let reserve_rent = ctx
+        .accounts
+        .rent
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.vault_reserve.lamports());
+    if reserve_rent > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.operator.to_account_info(),
+                to: ctx.accounts.vault_reserve.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, reserve_rent)?;
+    }
+
+    // Initialize validator list
+    let mut validator_list = ctx.accounts.validator_list.load_init()?;
+    validator_list.vault = vault.key();
+    validator_list.count = 0;
+    validator_list.bump = ctx.bumps.validator_list;
+    drop(validator_list);
+
+    // Initialize exchange-rate history
+    let mut rate_history = ctx.accounts.rate_history.load_init()?;
+    rate_history.vault = vault.key();
+    rate_history.bump = ctx.bumps.rate_history;
+    drop(rate_history);
+
     // Increment vault count
     factory.vault_count = factory
         .vault_count
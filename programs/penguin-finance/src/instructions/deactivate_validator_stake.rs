@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::stake::{self, state::StakeStateV2};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::read_stake_delegation;
+
+#[derive(Accounts)]
+pub struct DeactivateValidatorStake<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    /// CHECK: Validator vote account, only used for PDA derivation
+    pub validator_vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault stake account PDA for this validator
+    #[account(
+        mut,
+        seeds = [
+            STAKE_ACCOUNT_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump,
+        owner = stake::program::ID @ ErrorCode::StakeAccountNotFound,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// CHECK: Transient stake account PDA (receives partial unstakes)
+    #[account(
+        mut,
+        seeds = [
+            TRANSIENT_STAKE_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub transient_stake_account: UncheckedAccount<'info>,
+
+    /// Vault operator, or anyone once the vault is winding down
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> DeactivateValidatorStake<'info> {
+    fn deactivate(&self, stake_account: &AccountInfo<'info>, vault_seeds: &[&[u8]]) -> Result<()> {
+        invoke_signed(
+            &stake::instruction::deactivate_stake(stake_account.key, &self.vault.key()),
+            &[
+                stake_account.clone(),
+                self.clock.to_account_info(),
+                self.vault.to_account_info(),
+            ],
+            &[vault_seeds],
+        )?;
+        Ok(())
+    }
+}
+
+/// Deactivate `amount` lamports of a validator's stake. Amounts covering the
+/// whole stake account deactivate it in place; smaller amounts are split into
+/// the transient stake account first. Withdraw with `withdraw_deactivated_stake`
+/// once the cooldown is over.
+pub fn handler(ctx: Context<DeactivateValidatorStake>, amount: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    let vault_key = vault.key();
+    let validator = ctx.accounts.validator_vote_account.key();
+    let is_operator = ctx.accounts.authority.key() == vault.operator;
+    let winding_down = vault.status == VaultStatus::WindingDown;
+
+    require!(is_operator || winding_down, ErrorCode::Unauthorized);
+    require!(amount > 0, ErrorCode::InvalidUnstakeAmount);
+
+    let mut validator_list = ctx.accounts.validator_list.load_mut()?;
+    let entry = validator_list
+        .find_mut(&validator)
+        .ok_or(ErrorCode::ValidatorNotFound)?;
+    require!(
+        entry.status() == ValidatorStatus::Active,
+        ErrorCode::InvalidStakeState
+    );
+
+    let full = amount >= entry.active_lamports;
+    // Cranks on a winding-down vault may only unwind whole validators
+    require!(full || is_operator, ErrorCode::Unauthorized);
+
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+    let stake_account = ctx.accounts.stake_account.to_account_info();
+    let transient_stake_account = ctx.accounts.transient_stake_account.to_account_info();
+    let transient_in_use = transient_stake_account.owner == &stake::program::ID;
+
+    let (deactivated_account, deactivated_amount) = if full {
+        ctx.accounts.deactivate(&stake_account, vault_seeds)?;
+
+        // A pending top-up for this validator is unwound with it
+        if transient_in_use
+            && read_stake_delegation(&transient_stake_account)?.deactivation_epoch == u64::MAX
+        {
+            ctx.accounts.deactivate(&transient_stake_account, vault_seeds)?;
+        }
+
+        entry.set_status(ValidatorStatus::Deactivating);
+        (stake_account.key(), entry.active_lamports)
+    } else {
+        require!(!transient_in_use, ErrorCode::TransientStakeInUse);
+
+        let min_amount = Rent::get()?
+            .minimum_balance(StakeStateV2::size_of())
+            .checked_add(MIN_STAKE_AMOUNT)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(amount >= min_amount, ErrorCode::StakeTooSmall);
+
+        let transient_seeds = &[
+            TRANSIENT_STAKE_SEED,
+            vault_key.as_ref(),
+            validator.as_ref(),
+            &[ctx.bumps.transient_stake_account],
+        ];
+        for instruction in stake::instruction::split(
+            stake_account.key,
+            &vault_key,
+            amount,
+            transient_stake_account.key,
+        ) {
+            invoke_signed(
+                &instruction,
+                &[
+                    stake_account.clone(),
+                    transient_stake_account.clone(),
+                    vault.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                &[&vault_seeds[..], &transient_seeds[..]],
+            )?;
+        }
+        ctx.accounts.deactivate(&transient_stake_account, vault_seeds)?;
+
+        entry.active_lamports = entry
+            .active_lamports
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        entry.transient_lamports = amount;
+        (transient_stake_account.key(), amount)
+    };
+    entry.last_update_epoch = clock.epoch;
+    drop(validator_list);
+
+    emit!(StakeDeactivated {
+        vault: vault_key,
+        validator,
+        stake_account: deactivated_account,
+        amount: deactivated_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} deactivated {} SOL from validator {}",
+        vault.vault_id,
+        deactivated_amount as f64 / 1e9,
+        validator
+    );
+
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::stake::{
+    self,
+    state::{StakeAuthorize, StakeStateV2},
+};
+use anchor_spl::token_2022::{mint_to, MintTo, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::read_stake_state;
+
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = vault_token_mint,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    /// CHECK: Validator vote account, only used for PDA derivation
+    pub validator_vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault stake account PDA for this validator (merge destination)
+    #[account(
+        mut,
+        seeds = [
+            STAKE_ACCOUNT_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump,
+        owner = stake::program::ID @ ErrorCode::StakeAccountNotFound,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// CHECK: User's stake account, checked in the handler and merged away
+    #[account(
+        mut,
+        owner = stake::program::ID @ ErrorCode::StakeAccountNotFound,
+    )]
+    pub user_stake_account: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Staker and withdrawer of `user_stake_account`
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+
+    /// CHECK: Stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+}
+
+impl<'info> DepositStake<'info> {
+    fn authorize_vault(&self, authorize: StakeAuthorize) -> Result<()> {
+        invoke(
+            &stake::instruction::authorize(
+                self.user_stake_account.key,
+                self.user.key,
+                &self.vault.key(),
+                authorize,
+                None,
+            ),
+            &[
+                self.user_stake_account.to_account_info(),
+                self.clock.to_account_info(),
+                self.user.to_account_info(),
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+/// Merges an active stake account delegated to one of the vault's validators
+/// into the vault's stake account and mints shares for all of its lamports
+pub fn handler(ctx: Context<DepositStake>) -> Result<()> {
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_DEPOSITS)?;
+    require!(ctx.accounts.vault.is_accepting_deposits(), ErrorCode::VaultPaused);
+
+    let clock = Clock::get()?;
+    let validator = ctx.accounts.validator_vote_account.key();
+    let user_key = ctx.accounts.user.key();
+
+    let (meta, stake) = match read_stake_state(&ctx.accounts.user_stake_account)? {
+        StakeStateV2::Stake(meta, stake, _) => (meta, stake),
+        _ => return err!(ErrorCode::InvalidStakeState),
+    };
+    require_keys_eq!(meta.authorized.staker, user_key, ErrorCode::Unauthorized);
+    require_keys_eq!(meta.authorized.withdrawer, user_key, ErrorCode::Unauthorized);
+    require!(!meta.lockup.is_in_force(&clock, None), ErrorCode::InvalidStakeState);
+
+    // Delegated to this validator, past its activation epoch and not cooling down.
+    // The merge itself fails unless both accounts are fully active
+    let delegation = stake.delegation;
+    require_keys_eq!(delegation.voter_pubkey, validator, ErrorCode::ValidatorNotFound);
+    require!(
+        delegation.activation_epoch < clock.epoch && delegation.deactivation_epoch == u64::MAX,
+        ErrorCode::InvalidStakeState
+    );
+
+    let amount = ctx.accounts.user_stake_account.lamports();
+    require!(amount >= MIN_STAKE_AMOUNT, ErrorCode::DepositTooSmall);
+    require!(
+        ctx.accounts.vault.has_capacity(amount),
+        ErrorCode::VaultCapacityReached
+    );
+
+    let mut validator_list = ctx.accounts.validator_list.load_mut()?;
+    let entry = validator_list
+        .find_mut(&validator)
+        .ok_or(ErrorCode::ValidatorNotFound)?;
+    require!(
+        entry.status() == ValidatorStatus::Active,
+        ErrorCode::InvalidStakeState
+    );
+
+    // Take over the user's stake account, then fold it into the vault's
+    ctx.accounts.authorize_vault(StakeAuthorize::Staker)?;
+    ctx.accounts.authorize_vault(StakeAuthorize::Withdrawer)?;
+
+    let vault = &ctx.accounts.vault;
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+    for instruction in stake::instruction::merge(
+        ctx.accounts.stake_account.key,
+        ctx.accounts.user_stake_account.key,
+        &vault.key(),
+    ) {
+        invoke_signed(
+            &instruction,
+            &[
+                ctx.accounts.stake_account.to_account_info(),
+                ctx.accounts.user_stake_account.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.stake_history.to_account_info(),
+                vault.to_account_info(),
+            ],
+            &[&vault_seeds[..]],
+        )?;
+    }
+
+    entry.active_lamports = entry
+        .active_lamports
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    entry.last_update_epoch = clock.epoch;
+    drop(validator_list);
+
+    // Calculate shares to mint
+    let vault = &mut ctx.accounts.vault;
+    let shares_to_mint = vault.calculate_shares(amount)?;
+
+    // Update vault state
+    vault.total_staked = vault
+        .total_staked
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    vault.total_assets = vault
+        .total_assets
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    vault.total_shares = vault
+        .total_shares
+        .checked_add(shares_to_mint)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    // Mint vault tokens to user
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
+            to: ctx.accounts.user_vault_token_account.to_account_info(),
+            authority: vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    mint_to(mint_ctx, shares_to_mint)?;
+
+    emit!(StakeDeposited {
+        vault: vault.key(),
+        user: user_key,
+        validator,
+        stake_account: ctx.accounts.user_stake_account.key(),
+        sol_amount: amount,
+        vault_tokens_minted: shares_to_mint,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("User {} deposited a stake account into vault {}", user_key, vault.vault_id);
+    msg!("Stake: {} SOL with validator {}", amount as f64 / 1e9, validator);
+    msg!("Vault tokens minted: {}", shares_to_mint as f64 / 1e9);
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token_2022::{mint_to, MintTo, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
@@ -24,29 +25,39 @@ pub struct DepositToVault<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
     #[account(mut)]
-    pub vault_token_mint: Account<'info, Mint>,
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init_if_needed,
         payer = user,
         associated_token::mint = vault_token_mint,
         associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    pub user_vault_token_account: Account<'info, TokenAccount>,
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(ctx: Context<DepositToVault>, amount: u64) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
-    require!(ctx.accounts.vault.accepting_deposits, ErrorCode::VaultPaused);
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_DEPOSITS)?;
+    require!(ctx.accounts.vault.is_accepting_deposits(), ErrorCode::VaultPaused);
     require!(amount >= MIN_STAKE_AMOUNT, ErrorCode::DepositTooSmall);
     require!(
         ctx.accounts.vault.has_capacity(amount),
@@ -62,12 +73,12 @@ pub fn handler(ctx: Context<DepositToVault>, amount: u64) -> Result<()> {
     // Get exchange rate before deposit for event
     let exchange_rate = vault.exchange_rate()?;
 
-    // Transfer SOL from user to vault
+    // Transfer SOL from user to the vault reserve
     let transfer_ctx = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         Transfer {
             from: ctx.accounts.user.to_account_info(),
-            to: vault.to_account_info(),
+            to: ctx.accounts.vault_reserve.to_account_info(),
         },
     );
     transfer(transfer_ctx, amount)?;
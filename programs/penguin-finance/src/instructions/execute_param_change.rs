@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+        has_one = psol_controller,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [PSOL_CONTROLLER_SEED],
+        bump = psol_controller.bump,
+    )]
+    pub psol_controller: Account<'info, PsolController>,
+
+    #[account(
+        mut,
+        seeds = [
+            PENDING_PARAM_CHANGE_SEED,
+            factory.key().as_ref(),
+            &pending_change.change_id.to_le_bytes()
+        ],
+        bump = pending_change.bump,
+        has_one = factory,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_change: Account<'info, PendingParamChange>,
+
+    /// CHECK: Receives the rent back, checked against `pending_change.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Anyone can execute a change once its timelock has elapsed
+    pub executor: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteParamChange>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pending_change = &ctx.accounts.pending_change;
+
+    require!(
+        pending_change.is_executable(&clock),
+        ErrorCode::TimelockNotElapsed
+    );
+
+    let factory = &mut ctx.accounts.factory;
+    let psol_controller = &mut ctx.accounts.psol_controller;
+    let param = pending_change.param;
+    let new_value = pending_change.new_value;
+
+    // Re-check against the current state, other changes may have landed since queueing
+    param.validate(new_value, psol_controller)?;
+
+    let old_value = param.current_value(factory, psol_controller);
+    param.apply(new_value, factory, psol_controller);
+
+    emit!(ParamChangeExecuted {
+        factory: factory.key(),
+        change_id: pending_change.change_id,
+        param,
+        old_value,
+        new_value,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Executed change {} of {:?}: {} -> {}", pending_change.change_id, param, old_value, new_value);
+
+    Ok(())
+}
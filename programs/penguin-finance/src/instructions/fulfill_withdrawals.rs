@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct FulfillWithdrawals<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Anyone can advance the queue as liquidity comes in
+    pub cranker: Signer<'info>,
+}
+
+/// Remaining accounts: withdrawal tickets in ID order, starting at
+/// `vault.next_fulfill_ticket_id`. Tickets that were already closed are
+/// skipped; the crank stops at the first ticket the buffer cannot cover
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FulfillWithdrawals<'info>>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let vault_key = vault.key();
+    let clock = Clock::get()?;
+    let mut fulfilled: u32 = 0;
+
+    for ticket_info in ctx.remaining_accounts.iter() {
+        let ticket_id = vault.next_fulfill_ticket_id;
+        if ticket_id >= vault.next_ticket_id {
+            break;
+        }
+
+        let (expected_key, _) = Pubkey::find_program_address(
+            &[WITHDRAWAL_TICKET_SEED, vault_key.as_ref(), &ticket_id.to_le_bytes()],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            ticket_info.key(),
+            expected_key,
+            ErrorCode::InvalidWithdrawalTicket
+        );
+
+        // Closed before it was fulfilled
+        if ticket_info.data_is_empty() {
+            vault.next_fulfill_ticket_id = ticket_id + 1;
+            continue;
+        }
+
+        let mut ticket = Account::<WithdrawalTicket>::try_from(ticket_info)?;
+        let sol_amount = ticket.expected_sol_amount;
+
+        if !vault.reserve_for_ticket(sol_amount)? {
+            break;
+        }
+
+        ticket.ready_to_claim = true;
+        ticket.exit(ctx.program_id)?;
+        fulfilled += 1;
+
+        emit!(WithdrawalFulfilled {
+            vault: vault_key,
+            user: ticket.owner,
+            ticket_id,
+            sol_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!("Vault {} fulfilled {} withdrawal tickets", vault.vault_id, fulfilled);
+    msg!("Reserved: {} SOL, pending: {} SOL",
+        vault.reserved_sol as f64 / 1e9,
+        vault.pending_withdrawal_sol as f64 / 1e9
+    );
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+        has_one = authority,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<GrantRole>, member: Pubkey, role: Role) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.role_registry.grant(member, role)?;
+
+    emit!(RoleGranted {
+        factory: ctx.accounts.factory.key(),
+        member,
+        role,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Granted {:?} to {}", role, member);
+
+    Ok(())
+}
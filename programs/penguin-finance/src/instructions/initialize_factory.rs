@@ -25,6 +25,15 @@ pub struct InitializeFactory<'info> {
     )]
     pub psol_controller: Account<'info, PsolController>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = RoleRegistry::LEN,
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
     #[account(
         init,
         payer = authority,
@@ -55,13 +64,21 @@ pub fn handler(ctx: Context<InitializeFactory>) -> Result<()> {
 
     // Initialize factory
     factory.authority = ctx.accounts.authority.key();
+    factory.pending_authority = Pubkey::default();
     factory.treasury = ctx.accounts.treasury.key();
     factory.vault_count = 0;
     factory.protocol_fee_bps = PROTOCOL_FEE_BPS;
-    factory.paused = false;
+    factory.pause_flags = 0;
     factory.psol_mint = ctx.accounts.psol_mint.key();
     factory.psol_controller = psol_controller_key;
-    factory.bump = *ctx.bumps.get("factory").unwrap();
+    factory.timelock_unit = TimelockUnit::Epochs;
+    factory.timelock_delay = DEFAULT_TIMELOCK_DELAY_EPOCHS;
+    factory.oracle_quorum = DEFAULT_ORACLE_QUORUM;
+    factory.oracle_tolerance_bps = DEFAULT_ORACLE_TOLERANCE_BPS;
+    factory.max_reward_apr_bps = DEFAULT_MAX_REWARD_APR_BPS;
+    factory.max_rate_jump_bps = DEFAULT_MAX_RATE_JUMP_BPS;
+    factory.param_change_count = 0;
+    factory.bump = ctx.bumps.factory;
 
     // Initialize pSOL controller
     let psol_controller = &mut ctx.accounts.psol_controller;
@@ -73,7 +90,14 @@ pub fn handler(ctx: Context<InitializeFactory>) -> Result<()> {
     psol_controller.liquidation_threshold = LIQUIDATION_THRESHOLD;
     psol_controller.liquidation_bonus = LIQUIDATION_BONUS;
     psol_controller.active_positions = 0;
-    psol_controller.bump = *ctx.bumps.get("psol_controller").unwrap();
+    psol_controller.twap_window_epochs = 0;
+    psol_controller.bump = ctx.bumps.psol_controller;
+
+    // Initialize role registry, roles are granted by the authority afterwards
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.factory = factory_key;
+    role_registry.members = Vec::new();
+    role_registry.bump = ctx.bumps.role_registry;
 
     emit!(FactoryInitialized {
         factory: factory_key,
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{burn, Burn, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InstantUnstake<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = vault_token_mint,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, vault.key().as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = vault,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    /// Ticket redeeming the sold vault tokens, owned by the pool. The user
+    /// fronts the rent and the pool refunds it below
+    #[account(
+        init,
+        payer = user,
+        space = WithdrawalTicket::LEN,
+        seeds = [
+            WITHDRAWAL_TICKET_SEED,
+            vault.key().as_ref(),
+            &vault.next_ticket_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+
+    /// User's vault token account
+    #[account(
+        mut,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Sells vault tokens to the pool at the exchange rate minus the
+/// utilization fee. The pool redeems them through the withdrawal queue and
+/// funds the ticket rent, which comes back to it on claim
+pub fn handler(
+    ctx: Context<InstantUnstake>,
+    vault_token_amount: u64,
+    min_sol_out: u64,
+) -> Result<()> {
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_REQUESTS)?;
+    require!(vault_token_amount > 0, ErrorCode::InvalidUnstakeAmount);
+    require!(
+        ctx.accounts.vault.status != VaultStatus::Closed,
+        ErrorCode::InvalidVaultStatus
+    );
+    // The exchange rate is not trusted while a balance update awaits review
+    require!(!ctx.accounts.vault.quarantined, ErrorCode::QuarantinePending);
+
+    let vault = &mut ctx.accounts.vault;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let withdrawal_ticket = &mut ctx.accounts.withdrawal_ticket;
+    let clock = Clock::get()?;
+
+    // Price the swap before the shares leave the vault
+    let sol_value = vault.shares_to_sol(vault_token_amount)?;
+    let fee = liquidity_pool.unstake_fee(sol_value)?;
+    let sol_amount = sol_value
+        .checked_sub(fee)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    require!(sol_amount >= min_sol_out, ErrorCode::SlippageExceeded);
+
+    let ticket_rent = withdrawal_ticket.to_account_info().lamports();
+    let pool_outflow = sol_amount
+        .checked_add(ticket_rent)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(
+        liquidity_pool.sol_reserve >= pool_outflow,
+        ErrorCode::InsufficientPoolLiquidity
+    );
+
+    // Burn vault tokens
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
+            from: ctx.accounts.user_vault_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+    );
+    burn(burn_ctx, vault_token_amount)?;
+
+    // Queue the redemption on behalf of the pool
+    let (ticket_id, expected_sol) = vault.queue_withdrawal(vault_token_amount)?;
+
+    withdrawal_ticket.vault = vault.key();
+    withdrawal_ticket.user = ctx.accounts.user.key();
+    withdrawal_ticket.owner = liquidity_pool.key();
+    withdrawal_ticket.ticket_id = ticket_id;
+    withdrawal_ticket.vault_tokens_burned = vault_token_amount;
+    withdrawal_ticket.expected_sol_amount = expected_sol;
+    withdrawal_ticket.request_epoch = clock.epoch;
+    withdrawal_ticket.ready_to_claim = false;
+    withdrawal_ticket.bump = ctx.bumps.withdrawal_ticket;
+
+    // Update pool state, the fee stays with the LPs
+    liquidity_pool.sol_reserve = liquidity_pool
+        .sol_reserve
+        .checked_sub(pool_outflow)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    liquidity_pool.pending_redemption_sol = liquidity_pool
+        .pending_redemption_sol
+        .checked_add(expected_sol)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    liquidity_pool.lifetime_fees = liquidity_pool
+        .lifetime_fees
+        .checked_add(fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    // The pool account is owned by this program, so lamports move directly
+    liquidity_pool.sub_lamports(pool_outflow)?;
+    ctx.accounts.user.add_lamports(pool_outflow)?;
+
+    emit!(InstantUnstaked {
+        vault: vault.key(),
+        pool: liquidity_pool.key(),
+        user: ctx.accounts.user.key(),
+        ticket_id,
+        vault_tokens_sold: vault_token_amount,
+        sol_value,
+        fee,
+        sol_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Instant unstake by {}", ctx.accounts.user.key());
+    msg!("Vault tokens sold: {}", vault_token_amount as f64 / 1e9);
+    msg!("SOL value: {}", sol_value as f64 / 1e9);
+    msg!("Fee: {} SOL", fee as f64 / 1e9);
+    msg!("SOL received: {}", sol_amount as f64 / 1e9);
+
+    Ok(())
+}
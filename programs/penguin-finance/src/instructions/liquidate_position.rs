@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{burn, Burn, Token};
+use anchor_spl::token_2022::{transfer_checked, Token2022, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
@@ -20,8 +22,14 @@ pub struct LiquidatePosition<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
+    #[account(
+        seeds = [RATE_HISTORY_SEED, vault.key().as_ref()],
+        bump = rate_history.load()?.bump,
+    )]
+    pub rate_history: AccountLoader<'info, ExchangeRateHistory>,
+
     /// Vault token mint
-    pub vault_token_mint: Account<'info, Mint>,
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
@@ -31,7 +39,7 @@ pub struct LiquidatePosition<'info> {
     pub psol_controller: Account<'info, PsolController>,
 
     #[account(mut)]
-    pub psol_mint: Account<'info, Mint>,
+    pub psol_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
@@ -49,8 +57,9 @@ pub struct LiquidatePosition<'info> {
         mut,
         associated_token::mint = vault_token_mint,
         associated_token::authority = user_position,
+        associated_token::token_program = vault_token_program,
     )]
-    pub position_vault_token_account: Account<'info, TokenAccount>,
+    pub position_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Liquidator's pSOL account (pays debt)
     #[account(
@@ -58,32 +67,40 @@ pub struct LiquidatePosition<'info> {
         associated_token::mint = psol_mint,
         associated_token::authority = liquidator,
     )]
-    pub liquidator_psol_account: Account<'info, TokenAccount>,
+    pub liquidator_psol_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Liquidator's vault token account (receives collateral)
     #[account(
         mut,
         associated_token::mint = vault_token_mint,
         associated_token::authority = liquidator,
+        associated_token::token_program = vault_token_program,
     )]
-    pub liquidator_vault_token_account: Account<'info, TokenAccount>,
+    pub liquidator_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub liquidator: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub vault_token_program: Program<'info, Token2022>,
 }
 
 pub fn handler(ctx: Context<LiquidatePosition>) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_LIQUIDATIONS)?;
 
     let vault = &ctx.accounts.vault;
     let psol_controller = &mut ctx.accounts.psol_controller;
     let user_position = &mut ctx.accounts.user_position;
     let clock = Clock::get()?;
 
-    // Check if position is liquidatable
-    let exchange_rate = vault.exchange_rate()?;
+    // Check if position is liquidatable, valued at the more conservative of spot and TWAP
+    let exchange_rate = ctx.accounts.rate_history.load()?.valuation_rate(
+        vault.exchange_rate()?,
+        clock.epoch,
+        psol_controller.twap_window_epochs,
+    );
     let is_liquidatable = user_position.is_liquidatable(
         exchange_rate,
         psol_controller.liquidation_threshold,
@@ -123,15 +140,20 @@ pub fn handler(ctx: Context<LiquidatePosition>) -> Result<()> {
     let signer_seeds = &[&position_seeds[..]];
 
     let transfer_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        ctx.accounts.vault_token_program.to_account_info(),
+        TransferChecked {
             from: ctx.accounts.position_vault_token_account.to_account_info(),
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
             to: ctx.accounts.liquidator_vault_token_account.to_account_info(),
             authority: user_position.to_account_info(),
         },
         signer_seeds,
     );
-    transfer(transfer_ctx, total_collateral_to_liquidator)?;
+    transfer_checked(
+        transfer_ctx,
+        total_collateral_to_liquidator,
+        ctx.accounts.vault_token_mint.decimals,
+    )?;
 
     // Calculate collateral value
     let collateral_value = collateral
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::stake;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::read_stake_delegation;
+
+#[derive(Accounts)]
+pub struct MergeTransientStake<'info> {
+    #[account(
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    /// CHECK: Validator vote account, only used for PDA derivation
+    pub validator_vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault stake account PDA for this validator
+    #[account(
+        mut,
+        seeds = [
+            STAKE_ACCOUNT_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump,
+        owner = stake::program::ID @ ErrorCode::StakeAccountNotFound,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// CHECK: Transient stake account PDA for this validator
+    #[account(
+        mut,
+        seeds = [
+            TRANSIENT_STAKE_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump,
+        owner = stake::program::ID @ ErrorCode::StakeAccountNotFound,
+    )]
+    pub transient_stake_account: UncheckedAccount<'info>,
+
+    /// Anyone can merge a transient top-up once the stake program allows it
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+}
+
+/// The stake program rejects the merge until both delegations are in a
+/// mergeable state (both active, or both activating in the same epoch)
+pub fn handler(ctx: Context<MergeTransientStake>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    let amount = ctx.accounts.transient_stake_account.lamports();
+
+    // A deactivating transient holds a partial unstake, not a top-up
+    require!(
+        read_stake_delegation(&ctx.accounts.transient_stake_account)?.deactivation_epoch == u64::MAX,
+        ErrorCode::InvalidStakeState
+    );
+
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+
+    for instruction in stake::instruction::merge(
+        ctx.accounts.stake_account.key,
+        ctx.accounts.transient_stake_account.key,
+        &vault.key(),
+    ) {
+        invoke_signed(
+            &instruction,
+            &[
+                ctx.accounts.stake_account.to_account_info(),
+                ctx.accounts.transient_stake_account.to_account_info(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.stake_history.to_account_info(),
+                vault.to_account_info(),
+            ],
+            &[&vault_seeds[..]],
+        )?;
+    }
+
+    let mut validator_list = ctx.accounts.validator_list.load_mut()?;
+    let entry = validator_list
+        .find_mut(ctx.accounts.validator_vote_account.key)
+        .ok_or(ErrorCode::ValidatorNotFound)?;
+    entry.active_lamports = entry
+        .active_lamports
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    entry.transient_lamports = 0;
+    entry.last_update_epoch = clock.epoch;
+    drop(validator_list);
+
+    emit!(StakeMerged {
+        vault: vault.key(),
+        validator: ctx.accounts.validator_vote_account.key(),
+        stake_account: ctx.accounts.stake_account.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} merged {} SOL of transient stake for validator {}",
+        vault.vault_id,
+        amount as f64 / 1e9,
+        ctx.accounts.validator_vote_account.key()
+    );
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_spl::token::{mint_to, MintTo, Token};
+use anchor_spl::token_2022::{transfer_checked, Token2022, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
@@ -20,6 +22,12 @@ pub struct MintPsol<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
+    #[account(
+        seeds = [RATE_HISTORY_SEED, vault.key().as_ref()],
+        bump = rate_history.load()?.bump,
+    )]
+    pub rate_history: AccountLoader<'info, ExchangeRateHistory>,
+
     #[account(
         mut,
         seeds = [PSOL_CONTROLLER_SEED],
@@ -28,10 +36,10 @@ pub struct MintPsol<'info> {
     pub psol_controller: Account<'info, PsolController>,
 
     #[account(mut)]
-    pub psol_mint: Account<'info, Mint>,
+    pub psol_mint: InterfaceAccount<'info, Mint>,
 
     /// Vault token mint for this vault
-    pub vault_token_mint: Account<'info, Mint>,
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init_if_needed,
@@ -51,8 +59,9 @@ pub struct MintPsol<'info> {
         mut,
         associated_token::mint = vault_token_mint,
         associated_token::authority = user,
+        associated_token::token_program = vault_token_program,
     )]
-    pub user_vault_token_account: Account<'info, TokenAccount>,
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Position's vault token account (holds collateral)
     #[account(
@@ -60,8 +69,9 @@ pub struct MintPsol<'info> {
         payer = user,
         associated_token::mint = vault_token_mint,
         associated_token::authority = user_position,
+        associated_token::token_program = vault_token_program,
     )]
-    pub position_vault_token_account: Account<'info, TokenAccount>,
+    pub position_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// User's pSOL token account (receives minted pSOL)
     #[account(
@@ -70,13 +80,14 @@ pub struct MintPsol<'info> {
         associated_token::mint = psol_mint,
         associated_token::authority = user,
     )]
-    pub user_psol_account: Account<'info, TokenAccount>,
+    pub user_psol_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+    pub vault_token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -86,7 +97,9 @@ pub fn handler(
     collateral_amount: u64,
     psol_amount: u64,
 ) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_MINTING)?;
     require!(collateral_amount > 0, ErrorCode::InvalidCollateralAmount);
     require!(psol_amount > 0, ErrorCode::InvalidPsolAmount);
 
@@ -103,7 +116,7 @@ pub fn handler(
         user_position.collateral_amount = 0;
         user_position.psol_debt = 0;
         user_position.last_update_epoch = clock.epoch;
-        user_position.bump = *ctx.bumps.get("user_position").unwrap();
+        user_position.bump = ctx.bumps.user_position;
         
         psol_controller.active_positions = psol_controller
             .active_positions
@@ -111,8 +124,12 @@ pub fn handler(
             .ok_or(ErrorCode::ArithmeticOverflow)?;
     }
 
-    // Calculate collateral value
-    let exchange_rate = vault.exchange_rate()?;
+    // Calculate collateral value at the more conservative of spot and TWAP
+    let exchange_rate = ctx.accounts.rate_history.load()?.valuation_rate(
+        vault.exchange_rate()?,
+        clock.epoch,
+        psol_controller.twap_window_epochs,
+    );
     let collateral_value = collateral_amount
         .checked_mul(exchange_rate)
         .and_then(|v| v.checked_div(1_000_000_000))
@@ -147,14 +164,15 @@ pub fn handler(
 
     // Transfer vault tokens from user to position account
     let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        ctx.accounts.vault_token_program.to_account_info(),
+        TransferChecked {
             from: ctx.accounts.user_vault_token_account.to_account_info(),
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
             to: ctx.accounts.position_vault_token_account.to_account_info(),
             authority: ctx.accounts.user.to_account_info(),
         },
     );
-    transfer(transfer_ctx, collateral_amount)?;
+    transfer_checked(transfer_ctx, collateral_amount, ctx.accounts.vault_token_mint.decimals)?;
 
     // Mint pSOL to user
     let controller_seeds = &[
@@ -1,21 +1,102 @@
+// Every module exports its own `handler`; lib.rs calls them by path
+#![allow(ambiguous_glob_reexports)]
+
+pub mod accept_authority;
+pub mod accept_operator;
+pub mod add_liquidity;
 pub mod burn_psol;
+pub mod cancel_param_change;
+pub mod cancel_withdrawal;
+pub mod claim_pool_redemption;
 pub mod claim_withdrawal;
+pub mod claim_withdrawals;
+pub mod close_vault;
+pub mod crank_vault_balance;
+pub mod create_liquidity_pool;
 pub mod create_vault;
+pub mod deactivate_validator_stake;
+pub mod deposit_stake;
 pub mod deposit_to_vault;
+pub mod execute_param_change;
+pub mod fulfill_withdrawals;
+pub mod grant_role;
 pub mod initialize_factory;
+pub mod instant_unstake;
 pub mod liquidate_position;
+pub mod merge_transient_stake;
 pub mod mint_psol;
+pub mod pause_protocol;
+pub mod pause_vault;
+pub mod propose_authority;
+pub mod propose_operator;
+pub mod queue_param_change;
+pub mod remove_liquidity;
+pub mod rename_vault;
 pub mod request_withdrawal;
+pub mod resolve_quarantined_update;
+pub mod revoke_role;
+pub mod set_accepting_deposits;
 pub mod stake_from_vault;
+pub mod start_wind_down;
+pub mod submit_balance_report;
+pub mod sync_vault_reserve;
+pub mod transfer_withdrawal_ticket;
+pub mod unpause_protocol;
+pub mod unpause_vault;
+pub mod update_max_capacity;
+pub mod update_treasury;
 pub mod update_vault_balance;
+pub mod update_vault_fee;
+pub mod withdraw_deactivated_stake;
+pub mod withdraw_stake;
+pub mod withdraw_wound_down;
 
+pub use accept_authority::*;
+pub use accept_operator::*;
+pub use add_liquidity::*;
 pub use burn_psol::*;
+pub use cancel_param_change::*;
+pub use cancel_withdrawal::*;
+pub use claim_pool_redemption::*;
 pub use claim_withdrawal::*;
+pub use claim_withdrawals::*;
+pub use close_vault::*;
+pub use crank_vault_balance::*;
+pub use create_liquidity_pool::*;
 pub use create_vault::*;
+pub use deactivate_validator_stake::*;
+pub use deposit_stake::*;
 pub use deposit_to_vault::*;
+pub use execute_param_change::*;
+pub use fulfill_withdrawals::*;
+pub use grant_role::*;
 pub use initialize_factory::*;
+pub use instant_unstake::*;
 pub use liquidate_position::*;
+pub use merge_transient_stake::*;
 pub use mint_psol::*;
+pub use pause_protocol::*;
+pub use pause_vault::*;
+pub use propose_authority::*;
+pub use propose_operator::*;
+pub use queue_param_change::*;
+pub use remove_liquidity::*;
+pub use rename_vault::*;
 pub use request_withdrawal::*;
+pub use resolve_quarantined_update::*;
+pub use revoke_role::*;
+pub use set_accepting_deposits::*;
 pub use stake_from_vault::*;
-pub use update_vault_balance::*;
\ No newline at end of file
+pub use start_wind_down::*;
+pub use submit_balance_report::*;
+pub use sync_vault_reserve::*;
+pub use transfer_withdrawal_ticket::*;
+pub use unpause_protocol::*;
+pub use unpause_vault::*;
+pub use update_max_capacity::*;
+pub use update_treasury::*;
+pub use update_vault_balance::*;
+pub use update_vault_fee::*;
+pub use withdraw_deactivated_stake::*;
+pub use withdraw_stake::*;
+pub use withdraw_wound_down::*;
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct PauseProtocol<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub pauser: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<PauseProtocol>, flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        ErrorCode::InvalidPauseFlags
+    );
+    ctx.accounts
+        .role_registry
+        .require_role(&ctx.accounts.pauser.key(), Role::Pauser)?;
+
+    let factory = &mut ctx.accounts.factory;
+    let clock = Clock::get()?;
+
+    factory.pause_flags |= flags;
+
+    emit!(ProtocolPaused {
+        factory: factory.key(),
+        pauser: ctx.accounts.pauser.key(),
+        flags,
+        pause_flags: factory.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Protocol pause flags {:#04x} set by {}", flags, ctx.accounts.pauser.key());
+
+    Ok(())
+}
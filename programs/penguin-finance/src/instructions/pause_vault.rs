@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct PauseVault<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub pauser: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<PauseVault>, flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        ErrorCode::InvalidPauseFlags
+    );
+    ctx.accounts
+        .role_registry
+        .require_role(&ctx.accounts.pauser.key(), Role::Pauser)?;
+
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    vault.pause_flags |= flags;
+
+    emit!(VaultPaused {
+        vault: vault.key(),
+        pauser: ctx.accounts.pauser.key(),
+        flags,
+        pause_flags: vault.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} pause flags {:#04x} set by {}", vault.vault_id, flags, ctx.accounts.pauser.key());
+
+    Ok(())
+}
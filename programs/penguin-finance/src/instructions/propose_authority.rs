@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+        has_one = authority,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Proposing `Pubkey::default()` cancels any pending handover
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let factory = &mut ctx.accounts.factory;
+    let clock = Clock::get()?;
+
+    factory.pending_authority = new_authority;
+
+    emit!(AuthorityTransferProposed {
+        factory: factory.key(),
+        authority: ctx.accounts.authority.key(),
+        pending_authority: new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Authority transfer proposed to {}", new_authority);
+
+    Ok(())
+}
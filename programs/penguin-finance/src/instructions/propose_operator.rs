@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ProposeOperator<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub operator: Signer<'info>,
+}
+
+/// Proposing `Pubkey::default()` cancels any pending handover
+pub fn handler(ctx: Context<ProposeOperator>, new_operator: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    vault.pending_operator = new_operator;
+
+    emit!(OperatorTransferProposed {
+        vault: vault.key(),
+        operator: ctx.accounts.operator.key(),
+        pending_operator: new_operator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} operator transfer proposed to {}", vault.vault_id, new_operator);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct QueueParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+        has_one = psol_controller,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    #[account(
+        seeds = [PSOL_CONTROLLER_SEED],
+        bump = psol_controller.bump,
+    )]
+    pub psol_controller: Account<'info, PsolController>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingParamChange::LEN,
+        seeds = [
+            PENDING_PARAM_CHANGE_SEED,
+            factory.key().as_ref(),
+            &factory.param_change_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingParamChange>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueParamChange>, param: ProtocolParam, new_value: u64) -> Result<()> {
+    param.require_manager(
+        &ctx.accounts.proposer.key(),
+        &ctx.accounts.factory,
+        &ctx.accounts.role_registry,
+    )?;
+    param.validate(new_value, &ctx.accounts.psol_controller)?;
+
+    let factory = &mut ctx.accounts.factory;
+    let pending_change = &mut ctx.accounts.pending_change;
+    let clock = Clock::get()?;
+    let change_id = factory.param_change_count;
+
+    let queued_at = factory.timelock_unit.now(&clock);
+    let executable_at = queued_at
+        .checked_add(factory.timelock_delay)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    pending_change.factory = factory.key();
+    pending_change.change_id = change_id;
+    pending_change.param = param;
+    pending_change.new_value = new_value;
+    pending_change.unit = factory.timelock_unit;
+    pending_change.queued_at = queued_at;
+    pending_change.executable_at = executable_at;
+    pending_change.proposer = ctx.accounts.proposer.key();
+    pending_change.bump = ctx.bumps.pending_change;
+
+    factory.param_change_count = factory
+        .param_change_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ParamChangeQueued {
+        factory: factory.key(),
+        change_id,
+        param,
+        new_value,
+        executable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Queued change {} of {:?} to {}", change_id, param, new_value);
+    msg!("Executable at {:?} {}", pending_change.unit, executable_at);
+
+    Ok(())
+}
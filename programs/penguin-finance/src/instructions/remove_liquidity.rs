@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, liquidity_pool.vault.as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = lp_mint,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// LP tokens are worth their share of liquid SOL and pending redemptions,
+/// but only liquid SOL can be paid out
+pub fn handler(ctx: Context<RemoveLiquidity>, lp_amount: u64) -> Result<()> {
+    require!(lp_amount > 0, ErrorCode::InvalidLiquidityAmount);
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let clock = Clock::get()?;
+
+    let sol_amount = liquidity_pool.lp_tokens_to_sol(lp_amount, ctx.accounts.lp_mint.supply)?;
+    require!(
+        liquidity_pool.sol_reserve >= sol_amount,
+        ErrorCode::InsufficientPoolLiquidity
+    );
+
+    // Burn LP tokens
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            from: ctx.accounts.provider_lp_account.to_account_info(),
+            authority: ctx.accounts.provider.to_account_info(),
+        },
+    );
+    burn(burn_ctx, lp_amount)?;
+
+    liquidity_pool.sol_reserve = liquidity_pool
+        .sol_reserve
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    // The pool account is owned by this program, so lamports move directly
+    liquidity_pool.sub_lamports(sol_amount)?;
+    ctx.accounts.provider.add_lamports(sol_amount)?;
+
+    emit!(LiquidityRemoved {
+        pool: liquidity_pool.key(),
+        provider: ctx.accounts.provider.key(),
+        lp_tokens_burned: lp_amount,
+        sol_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Liquidity removed by {}", ctx.accounts.provider.key());
+    msg!("LP tokens burned: {}", lp_amount as f64 / 1e9);
+    msg!("SOL amount: {}", sol_amount as f64 / 1e9);
+
+    Ok(())
+}
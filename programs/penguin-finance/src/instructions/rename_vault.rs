@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RenameVault<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RenameVault>, vault_name: String) -> Result<()> {
+    require!(
+        vault_name.len() <= MAX_VAULT_NAME_LENGTH,
+        ErrorCode::VaultNameTooLong
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    vault.vault_name = vault_name.clone();
+
+    emit!(VaultRenamed {
+        vault: vault.key(),
+        vault_name,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} renamed to {}", vault.vault_id, vault.vault_name);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, Burn, Token, TokenAccount};
+use anchor_spl::token_2022::{burn, Burn, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
@@ -22,7 +23,7 @@ pub struct RequestWithdrawal<'info> {
     pub vault: Account<'info, Vault>,
 
     /// Vault token mint
-    pub vault_token_mint: Account<'info, Mint>,
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init,
@@ -31,8 +32,7 @@ pub struct RequestWithdrawal<'info> {
         seeds = [
             WITHDRAWAL_TICKET_SEED,
             vault.key().as_ref(),
-            user.key().as_ref(),
-            &vault.last_reward_epoch.to_le_bytes()
+            &vault.next_ticket_id.to_le_bytes()
         ],
         bump
     )]
@@ -43,28 +43,28 @@ pub struct RequestWithdrawal<'info> {
         mut,
         associated_token::mint = vault_token_mint,
         associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    pub user_vault_token_account: Account<'info, TokenAccount>,
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Program<'info, Token2022>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(ctx: Context<RequestWithdrawal>, vault_token_amount: u64) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_REQUESTS)?;
     require!(vault_token_amount > 0, ErrorCode::InvalidCollateralAmount);
 
     let vault = &mut ctx.accounts.vault;
     let withdrawal_ticket = &mut ctx.accounts.withdrawal_ticket;
     let clock = Clock::get()?;
 
-    // Calculate expected SOL
-    let expected_sol = vault.shares_to_sol(vault_token_amount)?;
-
     // Burn vault tokens
     let burn_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -76,28 +76,18 @@ pub fn handler(ctx: Context<RequestWithdrawal>, vault_token_amount: u64) -> Resu
     );
     burn(burn_ctx, vault_token_amount)?;
 
-    // Update vault state
-    vault.total_shares = vault
-        .total_shares
-        .checked_sub(vault_token_amount)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
-    
-    vault.total_assets = vault
-        .total_assets
-        .checked_sub(expected_sol)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
-
-    // Create withdrawal ticket
-    let ticket_id = clock.epoch;
+    // Update vault state and create withdrawal ticket
+    let (ticket_id, expected_sol) = vault.queue_withdrawal(vault_token_amount)?;
+
     withdrawal_ticket.vault = vault.key();
     withdrawal_ticket.user = ctx.accounts.user.key();
+    withdrawal_ticket.owner = ctx.accounts.user.key();
     withdrawal_ticket.ticket_id = ticket_id;
     withdrawal_ticket.vault_tokens_burned = vault_token_amount;
     withdrawal_ticket.expected_sol_amount = expected_sol;
     withdrawal_ticket.request_epoch = clock.epoch;
-    withdrawal_ticket.ready_to_claim = vault.buffered_sol >= expected_sol;
-    withdrawal_ticket.claimed = false;
-    withdrawal_ticket.bump = *ctx.bumps.get("withdrawal_ticket").unwrap();
+    withdrawal_ticket.ready_to_claim = false;
+    withdrawal_ticket.bump = ctx.bumps.withdrawal_ticket;
 
     emit!(WithdrawalRequested {
         vault: vault.key(),
@@ -111,7 +101,7 @@ pub fn handler(ctx: Context<RequestWithdrawal>, vault_token_amount: u64) -> Resu
     msg!("User {} requested withdrawal", ctx.accounts.user.key());
     msg!("Vault tokens burned: {}", vault_token_amount as f64 / 1e9);
     msg!("Expected SOL: {}", expected_sol as f64 / 1e9);
-    msg!("Ready to claim: {}", withdrawal_ticket.ready_to_claim);
+    msg!("Ticket ID: {}", ticket_id);
 
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ResolveQuarantinedUpdate<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        constraint = vault.quarantined @ ErrorCode::NoQuarantinedReport,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Must hold `Role::RiskManager`
+    pub reviewer: Signer<'info>,
+}
+
+/// Approving lets the next balance update or reserve sync through the bounds
+/// once, rejecting discards the quarantined update. Minting stays paused until the authority
+/// unpauses the vault
+pub fn handler(ctx: Context<ResolveQuarantinedUpdate>, approve: bool) -> Result<()> {
+    ctx.accounts
+        .role_registry
+        .require_role(&ctx.accounts.reviewer.key(), Role::RiskManager)?;
+
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+    let reported_total_staked = vault.quarantined_total_staked;
+
+    vault.quarantined = false;
+    vault.quarantined_total_staked = 0;
+    vault.quarantined_epoch = 0;
+    vault.breaker_override = approve;
+
+    emit!(QuarantineResolved {
+        vault: vault.key(),
+        reviewer: ctx.accounts.reviewer.key(),
+        approved: approve,
+        reported_total_staked,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Quarantined update of {} SOL for vault {} {}",
+        reported_total_staked as f64 / 1e9,
+        vault.vault_id,
+        if approve { "approved" } else { "rejected" }
+    );
+
+    Ok(())
+}
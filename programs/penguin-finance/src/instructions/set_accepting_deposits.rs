@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetAcceptingDeposits<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAcceptingDeposits>, accepting_deposits: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    require!(!vault.is_winding_down(), ErrorCode::InvalidVaultStatus);
+
+    let old_status = vault.status;
+    vault.status = if accepting_deposits {
+        VaultStatus::Active
+    } else {
+        VaultStatus::DepositsClosed
+    };
+
+    emit!(VaultStatusChanged {
+        vault: vault.key(),
+        old_status,
+        new_status: vault.status,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} accepting deposits: {}", vault.vault_id, accepting_deposits);
+
+    Ok(())
+}
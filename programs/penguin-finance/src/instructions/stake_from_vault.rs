@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::stake::{
+    self,
+    state::{Authorized, Lockup, StakeStateV2},
+};
+use anchor_lang::solana_program::system_instruction;
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::*;
 use crate::state::*;
+use crate::utils::transfer_from_reserve;
 
 #[derive(Accounts)]
 pub struct StakeFromVault<'info> {
@@ -23,10 +29,25 @@ pub struct StakeFromVault<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
     /// CHECK: Validator vote account to delegate to
+    #[account(owner = anchor_lang::solana_program::vote::program::ID)]
     pub validator_vote_account: UncheckedAccount<'info>,
 
-    /// CHECK: Stake account PDA (will be created)
+    /// CHECK: Stake account PDA (created on first delegation to this validator)
     #[account(
         mut,
         seeds = [
@@ -38,6 +59,18 @@ pub struct StakeFromVault<'info> {
     )]
     pub stake_account: UncheckedAccount<'info>,
 
+    /// CHECK: Transient stake account PDA (created when topping up an existing validator)
+    #[account(
+        mut,
+        seeds = [
+            TRANSIENT_STAKE_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub transient_stake_account: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub operator: Signer<'info>,
 
@@ -47,7 +80,7 @@ pub struct StakeFromVault<'info> {
     pub system_program: Program<'info, System>,
     
     /// CHECK: Stake program
-    #[account(address = anchor_lang::solana_program::stake::program::ID)]
+    #[account(address = stake::program::ID)]
     pub stake_program: UncheckedAccount<'info>,
     
     pub rent: Sysvar<'info, Rent>,
@@ -58,19 +91,151 @@ pub struct StakeFromVault<'info> {
     pub stake_history: UncheckedAccount<'info>,
 }
 
+impl<'info> StakeFromVault<'info> {
+    /// Fund a fresh stake account PDA from the vault reserve, initialize it with the
+    /// vault as staker and withdrawer, and delegate it to the validator
+    fn create_delegated_stake(
+        &self,
+        stake_account: &AccountInfo<'info>,
+        stake_account_seeds: &[&[u8]],
+        amount: u64,
+    ) -> Result<()> {
+        let vault = &self.vault;
+        let vault_key = vault.key();
+
+        transfer_from_reserve(
+            vault,
+            &self.vault_reserve.to_account_info(),
+            stake_account,
+            &self.system_program,
+            amount,
+        )?;
+
+        let stake_signer = &[stake_account_seeds];
+        invoke_signed(
+            &system_instruction::allocate(stake_account.key, StakeStateV2::size_of() as u64),
+            &[stake_account.clone(), self.system_program.to_account_info()],
+            stake_signer,
+        )?;
+        invoke_signed(
+            &system_instruction::assign(stake_account.key, &stake::program::ID),
+            &[stake_account.clone(), self.system_program.to_account_info()],
+            stake_signer,
+        )?;
+
+        invoke(
+            &stake::instruction::initialize(
+                stake_account.key,
+                &Authorized {
+                    staker: vault_key,
+                    withdrawer: vault_key,
+                },
+                &Lockup::default(),
+            ),
+            &[stake_account.clone(), self.rent.to_account_info()],
+        )?;
+
+        let vault_seeds = &[
+            VAULT_SEED,
+            vault.factory.as_ref(),
+            &vault.vault_id.to_le_bytes(),
+            &[vault.bump],
+        ];
+        invoke_signed(
+            &stake::instruction::delegate_stake(
+                stake_account.key,
+                &vault_key,
+                self.validator_vote_account.key,
+            ),
+            &[
+                stake_account.clone(),
+                self.validator_vote_account.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_config.to_account_info(),
+                vault.to_account_info(),
+            ],
+            &[&vault_seeds[..]],
+        )?;
+
+        Ok(())
+    }
+}
+
 pub fn handler(ctx: Context<StakeFromVault>, amount: u64) -> Result<()> {
-    require!(!ctx.accounts.factory.paused, ErrorCode::VaultPaused);
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_STAKING)?;
     require!(
-        ctx.accounts.vault.buffered_sol >= amount,
+        !ctx.accounts.vault.is_winding_down(),
+        ErrorCode::InvalidVaultStatus
+    );
+    // SOL owed to withdrawal tickets stays in the buffer
+    require!(
+        ctx.accounts.vault.free_buffer() >= amount,
         ErrorCode::InsufficientVaultBalance
     );
 
-    let vault = &mut ctx.accounts.vault;
+    // `amount` funds the whole stake account, including its rent-exempt reserve
+    let min_amount = ctx
+        .accounts
+        .rent
+        .minimum_balance(StakeStateV2::size_of())
+        .checked_add(MIN_STAKE_AMOUNT)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(amount >= min_amount, ErrorCode::StakeTooSmall);
+
     let clock = Clock::get()?;
+    let vault_key = ctx.accounts.vault.key();
+    let validator = ctx.accounts.validator_vote_account.key();
+
+    // A validator's first delegation creates its stake account. Later top-ups
+    // go through a transient account, merged by `merge_transient_stake` once
+    // both are active, since an active delegation cannot be increased in place.
+    let is_new_validator = ctx.accounts.stake_account.owner == &System::id();
+    let funded_account = if is_new_validator {
+        let stake_account = ctx.accounts.stake_account.to_account_info();
+        let seeds = &[
+            STAKE_ACCOUNT_SEED,
+            vault_key.as_ref(),
+            validator.as_ref(),
+            &[ctx.bumps.stake_account],
+        ];
+        ctx.accounts
+            .create_delegated_stake(&stake_account, seeds, amount)?;
+        stake_account
+    } else {
+        require!(
+            ctx.accounts.transient_stake_account.owner == &System::id(),
+            ErrorCode::TransientStakeInUse
+        );
+        let transient_stake_account = ctx.accounts.transient_stake_account.to_account_info();
+        let seeds = &[
+            TRANSIENT_STAKE_SEED,
+            vault_key.as_ref(),
+            validator.as_ref(),
+            &[ctx.bumps.transient_stake_account],
+        ];
+        ctx.accounts
+            .create_delegated_stake(&transient_stake_account, seeds, amount)?;
+        transient_stake_account
+    };
+
+    // Track the delegation per validator
+    let mut validator_list = ctx.accounts.validator_list.load_mut()?;
+    let entry = validator_list.find_or_add(validator, ctx.accounts.stake_account.key())?;
+    if is_new_validator {
+        entry.active_lamports = amount;
+        entry.transient_lamports = 0;
+        entry.set_status(ValidatorStatus::Active);
+    } else {
+        entry.transient_lamports = amount;
+    }
+    entry.last_update_epoch = clock.epoch;
+    drop(validator_list);
+
+    let vault = &mut ctx.accounts.vault;
 
-    // For simplicity in hackathon, we'll track the delegation intent
-    // In production, this would create actual stake accounts via CPI to stake program
-    
     // Update vault state
     vault.buffered_sol = vault
         .buffered_sol
@@ -82,15 +247,17 @@ pub fn handler(ctx: Context<StakeFromVault>, amount: u64) -> Result<()> {
         .checked_add(amount)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
     
-    vault.active_validators = vault
-        .active_validators
-        .checked_add(1)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    if is_new_validator {
+        vault.active_validators = vault
+            .active_validators
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
 
     emit!(StakeDelegated {
-        vault: vault.key(),
-        validator: ctx.accounts.validator_vote_account.key(),
-        stake_account: ctx.accounts.stake_account.key(),
+        vault: vault_key,
+        validator,
+        stake_account: funded_account.key(),
         amount,
         timestamp: clock.unix_timestamp,
     });
@@ -98,8 +265,8 @@ pub fn handler(ctx: Context<StakeFromVault>, amount: u64) -> Result<()> {
     msg!("Vault {} delegated {} SOL to validator {}", 
         vault.vault_id, 
         amount as f64 / 1e9,
-        ctx.accounts.validator_vote_account.key()
+        validator
     );
 
     Ok(())
-}
\ No newline at end of file
+}
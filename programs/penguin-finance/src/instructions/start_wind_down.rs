@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct StartWindDown<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub operator: Signer<'info>,
+}
+
+/// Winding down is irreversible: deposits and staking stop for good and
+/// anyone can deactivate and withdraw the remaining stake
+pub fn handler(ctx: Context<StartWindDown>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    require!(!vault.is_winding_down(), ErrorCode::InvalidVaultStatus);
+
+    let old_status = vault.status;
+    vault.status = VaultStatus::WindingDown;
+
+    emit!(VaultStatusChanged {
+        vault: vault.key(),
+        old_status,
+        new_status: vault.status,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} is winding down", vault.vault_id);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SubmitBalanceReport<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = BalanceReport::LEN,
+        seeds = [BALANCE_REPORT_SEED, vault.key().as_ref(), &clock.epoch.to_le_bytes()],
+        bump,
+    )]
+    pub balance_report: Account<'info, BalanceReport>,
+
+    /// Must hold `Role::OracleReporter`
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitBalanceReport>, total_staked: u64) -> Result<()> {
+    let reporter = ctx.accounts.reporter.key();
+    ctx.accounts
+        .role_registry
+        .require_role(&reporter, Role::OracleReporter)?;
+
+    let factory = &ctx.accounts.factory;
+    let vault = &ctx.accounts.vault;
+    let balance_report = &mut ctx.accounts.balance_report;
+    let clock = Clock::get()?;
+
+    // First report of the epoch fixes the quorum and tolerance
+    if balance_report.vault == Pubkey::default() {
+        balance_report.vault = vault.key();
+        balance_report.epoch = clock.epoch;
+        balance_report.quorum = factory.oracle_quorum;
+        balance_report.tolerance_bps = factory.oracle_tolerance_bps;
+        balance_report.reports = Vec::new();
+        balance_report.finalized = false;
+        balance_report.median_total_staked = 0;
+        balance_report.bump = ctx.bumps.balance_report;
+    }
+
+    let finalized = balance_report.submit(reporter, total_staked)?;
+
+    emit!(BalanceReportSubmitted {
+        vault: vault.key(),
+        epoch: clock.epoch,
+        reporter,
+        total_staked,
+        report_count: balance_report.reports.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Balance report for vault {} epoch {}: {} SOL from {}",
+        vault.vault_id,
+        clock.epoch,
+        total_staked as f64 / 1e9,
+        reporter
+    );
+
+    if !finalized {
+        return Ok(());
+    }
+
+    let median_total_staked = balance_report.median_total_staked;
+    let mut deviant_count = 0u8;
+    for report in balance_report.deviants() {
+        deviant_count += 1;
+        emit!(OracleReportDeviated {
+            vault: vault.key(),
+            epoch: clock.epoch,
+            reporter: report.reporter,
+            total_staked: report.total_staked,
+            median_total_staked,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(BalanceReportFinalized {
+        vault: vault.key(),
+        epoch: clock.epoch,
+        median_total_staked,
+        report_count: balance_report.reports.len() as u8,
+        deviant_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Balance report finalized at {} SOL, {} deviant reports",
+        median_total_staked as f64 / 1e9,
+        deviant_count
+    );
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::mint_fee_shares;
+
+#[derive(Accounts)]
+pub struct SyncVaultReserve<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = vault_token_mint,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Vault operator, checked against `vault.operator`
+    #[account(address = vault.operator)]
+    pub operator: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol treasury, checked against `factory.treasury`
+    #[account(address = factory.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Operator's vault token account (receives fee shares)
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = operator,
+        associated_token::token_program = token_program,
+    )]
+    pub operator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury's vault token account (receives protocol fee shares)
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Anyone can reconcile the reserve
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Reconcile `buffered_sol` with the reserve's lamports above rent. SOL sent
+/// straight to the reserve is booked as rewards within the circuit-breaker
+/// bounds; a reserve holding less than the books say breaks an invariant, so
+/// minting is paused for review
+pub fn handler(ctx: Context<SyncVaultReserve>) -> Result<()> {
+    require!(
+        ctx.accounts.vault.status != VaultStatus::Closed,
+        ErrorCode::InvalidVaultStatus
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+    let reserve_balance = ctx
+        .accounts
+        .vault_reserve
+        .lamports()
+        .saturating_sub(ctx.accounts.rent.minimum_balance(0));
+
+    if reserve_balance < vault.buffered_sol {
+        let shortfall = vault.buffered_sol - reserve_balance;
+        vault.pause_flags |= PAUSE_MINTING;
+
+        emit!(ReserveShortfallDetected {
+            vault: vault.key(),
+            reserve_balance,
+            buffered_sol: vault.buffered_sol,
+            shortfall,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Vault {} reserve is {} SOL short of its buffer",
+            vault.vault_id,
+            shortfall as f64 / 1e9
+        );
+        msg!("Minting paused pending review");
+        return Ok(());
+    }
+
+    let surplus = reserve_balance - vault.buffered_sol;
+    if surplus == 0 {
+        msg!("Vault {} reserve in sync", vault.vault_id);
+        return Ok(());
+    }
+
+    // A pending review already holds minting and owns the quarantine slot
+    require!(!vault.quarantined, ErrorCode::QuarantinePending);
+
+    // Donations move the exchange rate like rewards do, so the same caps apply
+    let factory = &ctx.accounts.factory;
+    if !vault.surplus_within_bounds(
+        surplus,
+        clock.epoch,
+        factory.max_reward_apr_bps,
+        factory.max_rate_jump_bps,
+    )? {
+        let total_staked = vault.total_staked;
+        vault.quarantine(total_staked, clock.epoch);
+
+        emit!(ReserveSurplusQuarantined {
+            vault: vault.key(),
+            surplus,
+            total_assets: vault.total_assets,
+            max_reward_apr_bps: factory.max_reward_apr_bps,
+            max_rate_jump_bps: factory.max_rate_jump_bps,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Circuit breaker tripped for vault {}: {} SOL reserve surplus against {} SOL of assets",
+            vault.vault_id,
+            surplus as f64 / 1e9,
+            vault.total_assets as f64 / 1e9
+        );
+        msg!("Surplus left unbooked and minting paused pending review");
+        return Ok(());
+    }
+
+    let distribution = vault.book_reserve_surplus(
+        surplus,
+        factory.protocol_fee_bps,
+        clock.epoch,
+    )?;
+
+    mint_fee_shares(
+        vault,
+        &ctx.accounts.vault_token_mint,
+        &ctx.accounts.operator_token_account,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.token_program,
+        &distribution,
+    )?;
+
+    let new_exchange_rate = vault.exchange_rate()?;
+
+    emit!(ReserveSurplusBooked {
+        vault: vault.key(),
+        surplus,
+        protocol_fee: distribution.protocol_fee,
+        operator_fee: distribution.operator_fee,
+        staker_rewards: distribution.staker_rewards,
+        new_exchange_rate,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} booked {} SOL of reserve surplus as rewards",
+        vault.vault_id,
+        surplus as f64 / 1e9
+    );
+    msg!("New exchange rate: {}", new_exchange_rate as f64 / 1e9);
+
+    Ok(())
+}
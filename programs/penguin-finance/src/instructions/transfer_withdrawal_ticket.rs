@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct TransferWithdrawalTicket<'info> {
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_TICKET_SEED,
+            withdrawal_ticket.vault.as_ref(),
+            &withdrawal_ticket.ticket_id.to_le_bytes()
+        ],
+        bump = withdrawal_ticket.bump,
+        has_one = owner @ ErrorCode::Unauthorized,
+    )]
+    pub withdrawal_ticket: Account<'info, WithdrawalTicket>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: New ticket owner. Program accounts such as liquidity pools
+    /// cannot take over tickets they did not create
+    #[account(constraint = *new_owner.owner != crate::ID @ ErrorCode::Unauthorized)]
+    pub new_owner: UncheckedAccount<'info>,
+}
+
+/// The new owner can claim or cancel the ticket, and transfer it again
+pub fn handler(ctx: Context<TransferWithdrawalTicket>) -> Result<()> {
+    let new_owner = ctx.accounts.new_owner.key();
+    let withdrawal_ticket = &mut ctx.accounts.withdrawal_ticket;
+    let clock = Clock::get()?;
+    let old_owner = withdrawal_ticket.owner;
+
+    withdrawal_ticket.owner = new_owner;
+
+    emit!(WithdrawalTicketTransferred {
+        vault: withdrawal_ticket.vault,
+        ticket_id: withdrawal_ticket.ticket_id,
+        from: old_owner,
+        to: new_owner,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Withdrawal ticket {} transferred: {} -> {}",
+        withdrawal_ticket.ticket_id,
+        old_owner,
+        new_owner
+    );
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UnpauseProtocol<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+        has_one = authority,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnpauseProtocol>, flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        ErrorCode::InvalidPauseFlags
+    );
+
+    let factory = &mut ctx.accounts.factory;
+    let clock = Clock::get()?;
+
+    factory.pause_flags &= !flags;
+
+    emit!(ProtocolUnpaused {
+        factory: factory.key(),
+        authority: ctx.accounts.authority.key(),
+        flags,
+        pause_flags: factory.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Protocol pause flags {:#04x} cleared by {}", flags, ctx.accounts.authority.key());
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UnpauseVault<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+        has_one = authority,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnpauseVault>, flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        ErrorCode::InvalidPauseFlags
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    vault.pause_flags &= !flags;
+
+    emit!(VaultUnpaused {
+        vault: vault.key(),
+        authority: ctx.accounts.authority.key(),
+        flags,
+        pause_flags: vault.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} pause flags {:#04x} cleared by {}", vault.vault_id, flags, ctx.accounts.authority.key());
+
+    Ok(())
+}
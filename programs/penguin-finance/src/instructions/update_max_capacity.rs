@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxCapacity<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub operator: Signer<'info>,
+}
+
+/// Lowering the capacity below `total_assets` only blocks new deposits
+pub fn handler(ctx: Context<UpdateMaxCapacity>, new_max_capacity: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+    let old_max_capacity = vault.max_capacity;
+
+    vault.max_capacity = new_max_capacity;
+
+    emit!(VaultCapacityUpdated {
+        vault: vault.key(),
+        old_max_capacity,
+        new_max_capacity,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} max capacity: {} -> {} SOL",
+        vault.vault_id,
+        old_max_capacity as f64 / 1e9,
+        new_max_capacity as f64 / 1e9
+    );
+
+    Ok(())
+}
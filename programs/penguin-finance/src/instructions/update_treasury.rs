@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, factory.key().as_ref()],
+        bump = role_registry.bump,
+        has_one = factory,
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub treasury_manager: Signer<'info>,
+
+    /// CHECK: Treasury can be any account
+    pub new_treasury: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateTreasury>) -> Result<()> {
+    ctx.accounts
+        .role_registry
+        .require_role(&ctx.accounts.treasury_manager.key(), Role::TreasuryManager)?;
+
+    let factory = &mut ctx.accounts.factory;
+    let clock = Clock::get()?;
+    let old_treasury = factory.treasury;
+
+    factory.treasury = ctx.accounts.new_treasury.key();
+
+    emit!(TreasuryUpdated {
+        factory: factory.key(),
+        old_treasury,
+        new_treasury: factory.treasury,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Treasury updated: {} -> {}", old_treasury, factory.treasury);
+
+    Ok(())
+}
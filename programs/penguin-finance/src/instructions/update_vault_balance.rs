@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
 use crate::events::*;
 use crate::state::*;
+use crate::utils::mint_fee_shares;
 
 #[derive(Accounts)]
 pub struct UpdateVaultBalance<'info> {
@@ -24,175 +26,183 @@ pub struct UpdateVaultBalance<'info> {
     pub vault: Account<'info, Vault>,
 
     #[account(mut)]
-    pub vault_token_mint: Account<'info, Mint>,
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// Oracle reports for this epoch, must have reached quorum
+    #[account(
+        seeds = [BALANCE_REPORT_SEED, vault.key().as_ref(), &clock.epoch.to_le_bytes()],
+        bump = balance_report.bump,
+        has_one = vault,
+        constraint = balance_report.finalized @ ErrorCode::BalanceReportNotFinalized,
+    )]
+    pub balance_report: Account<'info, BalanceReport>,
+
+    /// Reward snapshot for this epoch, its PDA allows one update per epoch
+    #[account(
+        init,
+        payer = cranker,
+        space = EpochSnapshot::LEN,
+        seeds = [EPOCH_SNAPSHOT_SEED, vault.key().as_ref(), &clock.epoch.to_le_bytes()],
+        bump,
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+
+    #[account(
+        mut,
+        seeds = [RATE_HISTORY_SEED, vault.key().as_ref()],
+        bump = rate_history.load()?.bump,
+    )]
+    pub rate_history: AccountLoader<'info, ExchangeRateHistory>,
+
+    /// CHECK: Vault operator, checked against `vault.operator`
+    #[account(address = vault.operator)]
+    pub operator: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol treasury, checked against `factory.treasury`
+    #[account(address = factory.treasury)]
+    pub treasury: UncheckedAccount<'info>,
 
     /// Operator's vault token account (receives fee shares)
     #[account(
         init_if_needed,
-        payer = oracle,
+        payer = cranker,
         associated_token::mint = vault_token_mint,
-        associated_token::authority = vault.operator,
+        associated_token::authority = operator,
+        associated_token::token_program = token_program,
     )]
-    pub operator_token_account: Account<'info, TokenAccount>,
+    pub operator_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// Treasury's vault token account (receives protocol fee shares)
     #[account(
         init_if_needed,
-        payer = oracle,
+        payer = cranker,
         associated_token::mint = vault_token_mint,
-        associated_token::authority = factory.treasury,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
     )]
-    pub treasury_token_account: Account<'info, TokenAccount>,
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// Oracle/keeper that reports balances
+    /// Anyone can apply a finalized balance report
     #[account(mut)]
-    pub oracle: Signer<'info>,
+    pub cranker: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<UpdateVaultBalance>, new_total_staked: u64) -> Result<()> {
+/// Books the median of the oracle reports finalized for this epoch. The
+/// crank reading the stake accounts is authoritative; this is the fallback
+/// for when it cannot run, and whichever path books first claims the epoch
+/// snapshot
+pub fn handler(ctx: Context<UpdateVaultBalance>) -> Result<()> {
+    let new_total_staked = ctx.accounts.balance_report.median_total_staked;
     let vault = &mut ctx.accounts.vault;
-    let factory = &ctx.accounts.factory;
     let clock = Clock::get()?;
 
-    // Calculate rewards earned
-    let old_total_staked = vault.total_staked;
-    
-    require!(
-        new_total_staked >= old_total_staked,
-        ErrorCode::InvalidVaultState
-    );
-
-    let rewards = new_total_staked
-        .checked_sub(old_total_staked)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    let epoch_snapshot = &mut ctx.accounts.epoch_snapshot;
+    epoch_snapshot.record_start(vault, clock.epoch, ctx.bumps.epoch_snapshot)?;
 
-    if rewards == 0 {
-        msg!("No rewards to distribute");
+    let factory = &ctx.accounts.factory;
+    if !vault.within_reward_bounds(
+        new_total_staked,
+        clock.epoch,
+        factory.max_reward_apr_bps,
+        factory.max_rate_jump_bps,
+    )? {
+        vault.quarantine(new_total_staked, clock.epoch);
+        epoch_snapshot.record_distribution(&RewardDistribution::default(), vault.exchange_rate()?);
+
+        emit!(CircuitBreakerTripped {
+            vault: vault.key(),
+            epoch: clock.epoch,
+            total_staked: vault.total_staked,
+            reported_total_staked: new_total_staked,
+            max_reward_apr_bps: factory.max_reward_apr_bps,
+            max_rate_jump_bps: factory.max_rate_jump_bps,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Circuit breaker tripped for vault {}: {} SOL reported against {} SOL staked",
+            vault.vault_id,
+            new_total_staked as f64 / 1e9,
+            vault.total_staked as f64 / 1e9
+        );
+        msg!("Balance update quarantined and minting paused pending review");
         return Ok(());
     }
 
-    // Calculate fees
-    let protocol_fee = rewards
-        .checked_mul(factory.protocol_fee_bps as u64)
-        .and_then(|v| v.checked_div(BASIS_POINTS_DIVISOR))
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-
-    let remaining_after_protocol = rewards
-        .checked_sub(protocol_fee)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
-
-    let operator_fee = remaining_after_protocol
-        .checked_mul(vault.fee_basis_points as u64)
-        .and_then(|v| v.checked_div(BASIS_POINTS_DIVISOR))
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-
-    let staker_rewards = remaining_after_protocol
-        .checked_sub(operator_fee)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
-
-    // Calculate fee shares to mint
-    let total_fee_amount = protocol_fee
-        .checked_add(operator_fee)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-
-    let fee_shares = if vault.total_shares > 0 {
-        total_fee_amount
-            .checked_mul(vault.total_shares)
-            .and_then(|v| v.checked_div(vault.total_assets))
-            .ok_or(ErrorCode::ArithmeticOverflow)?
-    } else {
-        0
-    };
-
-    // Split fee shares between protocol and operator
-    let protocol_shares = if fee_shares > 0 {
-        fee_shares
-            .checked_mul(protocol_fee)
-            .and_then(|v| v.checked_div(total_fee_amount))
-            .ok_or(ErrorCode::ArithmeticOverflow)?
-    } else {
-        0
-    };
-
-    let operator_shares = fee_shares
-        .checked_sub(protocol_shares)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
-
-    // Update vault state
-    vault.total_staked = new_total_staked;
-    vault.total_assets = vault
-        .total_assets
-        .checked_add(rewards)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    vault.total_shares = vault
-        .total_shares
-        .checked_add(fee_shares)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    vault.lifetime_rewards = vault
-        .lifetime_rewards
-        .checked_add(rewards)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    vault.last_reward_epoch = clock.epoch;
-
-    // Mint fee shares
-    let vault_seeds = &[
-        VAULT_SEED,
-        vault.factory.as_ref(),
-        &vault.vault_id.to_le_bytes(),
-        &[vault.bump],
-    ];
-    let signer_seeds = &[&vault_seeds[..]];
-
-    if operator_shares > 0 {
-        let mint_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.vault_token_mint.to_account_info(),
-                to: ctx.accounts.operator_token_account.to_account_info(),
-                authority: vault.to_account_info(),
-            },
-            signer_seeds,
-        );
-        mint_to(mint_ctx, operator_shares)?;
+    let distribution = vault.distribute_rewards(
+        new_total_staked,
+        factory.protocol_fee_bps,
+        clock.epoch,
+    )?;
+
+    epoch_snapshot.record_distribution(&distribution, vault.exchange_rate()?);
+
+    ctx.accounts.rate_history.load_mut()?.push(RateHistoryEntry {
+        epoch: clock.epoch,
+        total_assets: vault.total_assets,
+        total_shares: vault.total_shares,
+        exchange_rate: epoch_snapshot.exchange_rate,
+        rewards: distribution.rewards,
+    });
+
+    if distribution.loss > 0 {
+        let new_exchange_rate = vault.exchange_rate()?;
+
+        emit!(LossRealized {
+            vault: vault.key(),
+            epoch: clock.epoch,
+            loss: distribution.loss,
+            total_assets: vault.total_assets,
+            new_exchange_rate,
+            high_water_mark: vault.high_water_mark,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Loss realized for vault {}", vault.vault_id);
+        msg!("Loss: {} SOL", distribution.loss as f64 / 1e9);
+        msg!("New exchange rate: {}", new_exchange_rate as f64 / 1e9);
+        return Ok(());
     }
 
-    if protocol_shares > 0 {
-        let mint_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.vault_token_mint.to_account_info(),
-                to: ctx.accounts.treasury_token_account.to_account_info(),
-                authority: vault.to_account_info(),
-            },
-            signer_seeds,
-        );
-        mint_to(mint_ctx, protocol_shares)?;
+    if distribution.rewards == 0 {
+        msg!("No rewards to distribute");
+        return Ok(());
     }
 
+    mint_fee_shares(
+        vault,
+        &ctx.accounts.vault_token_mint,
+        &ctx.accounts.operator_token_account,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.token_program,
+        &distribution,
+    )?;
+
     let new_exchange_rate = vault.exchange_rate()?;
 
     emit!(RewardsDistributed {
         vault: vault.key(),
         epoch: clock.epoch,
-        total_rewards: rewards,
-        protocol_fee,
-        operator_fee,
-        staker_rewards,
+        total_rewards: distribution.rewards,
+        protocol_fee: distribution.protocol_fee,
+        operator_fee: distribution.operator_fee,
+        staker_rewards: distribution.staker_rewards,
         new_exchange_rate,
         timestamp: clock.unix_timestamp,
     });
 
     msg!("Rewards distributed for vault {}", vault.vault_id);
-    msg!("Total rewards: {} SOL", rewards as f64 / 1e9);
-    msg!("Protocol fee: {} SOL", protocol_fee as f64 / 1e9);
-    msg!("Operator fee: {} SOL", operator_fee as f64 / 1e9);
-    msg!("Staker rewards: {} SOL", staker_rewards as f64 / 1e9);
+    msg!("Total rewards: {} SOL", distribution.rewards as f64 / 1e9);
+    msg!("Protocol fee: {} SOL", distribution.protocol_fee as f64 / 1e9);
+    msg!("Operator fee: {} SOL", distribution.operator_fee as f64 / 1e9);
+    msg!("Staker rewards: {} SOL", distribution.staker_rewards as f64 / 1e9);
     msg!("New exchange rate: {}", new_exchange_rate as f64 / 1e9);
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateVaultFee<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = operator,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub operator: Signer<'info>,
+}
+
+/// Fee decreases apply immediately and cancel any announced increase.
+/// Increases apply after `FEE_INCREASE_NOTICE_EPOCHS`.
+pub fn handler(ctx: Context<UpdateVaultFee>, new_fee_basis_points: u16) -> Result<()> {
+    require!(
+        new_fee_basis_points <= MAX_OPERATOR_FEE_BPS,
+        ErrorCode::OperatorFeeTooHigh
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    vault.apply_pending_fee(clock.epoch);
+    let old_fee_basis_points = vault.fee_basis_points;
+
+    let effective_epoch = if new_fee_basis_points <= old_fee_basis_points {
+        vault.fee_basis_points = new_fee_basis_points;
+        vault.pending_fee_basis_points = 0;
+        vault.pending_fee_epoch = 0;
+        clock.epoch
+    } else {
+        let effective_epoch = clock
+            .epoch
+            .checked_add(FEE_INCREASE_NOTICE_EPOCHS)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vault.pending_fee_basis_points = new_fee_basis_points;
+        vault.pending_fee_epoch = effective_epoch;
+        effective_epoch
+    };
+
+    emit!(VaultFeeUpdated {
+        vault: vault.key(),
+        old_fee_basis_points,
+        new_fee_basis_points,
+        effective_epoch,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} fee: {}% -> {}% from epoch {}",
+        vault.vault_id,
+        old_fee_basis_points as f64 / 100.0,
+        new_fee_basis_points as f64 / 100.0,
+        effective_epoch
+    );
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::stake;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::read_stake_delegation;
+
+#[derive(Accounts)]
+pub struct WithdrawDeactivatedStake<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    /// CHECK: Validator vote account, only used for PDA derivation
+    pub validator_vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault stake account PDA for this validator
+    #[account(
+        mut,
+        seeds = [
+            STAKE_ACCOUNT_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// CHECK: Transient stake account PDA for this validator
+    #[account(
+        mut,
+        seeds = [
+            TRANSIENT_STAKE_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub transient_stake_account: UncheckedAccount<'info>,
+
+    /// Anyone can withdraw stake whose cooldown is over
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+}
+
+impl<'info> WithdrawDeactivatedStake<'info> {
+    /// Withdraw every lamport of a stake account into the vault reserve, closing it
+    fn withdraw_all(&self, stake_account: &AccountInfo<'info>) -> Result<u64> {
+        let vault = &self.vault;
+        let lamports = stake_account.lamports();
+        let vault_seeds = &[
+            VAULT_SEED,
+            vault.factory.as_ref(),
+            &vault.vault_id.to_le_bytes(),
+            &[vault.bump],
+        ];
+
+        invoke_signed(
+            &stake::instruction::withdraw(
+                stake_account.key,
+                &vault.key(),
+                self.vault_reserve.key,
+                lamports,
+                None,
+            ),
+            &[
+                stake_account.clone(),
+                self.vault_reserve.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                vault.to_account_info(),
+            ],
+            &[&vault_seeds[..]],
+        )?;
+
+        Ok(lamports)
+    }
+}
+
+/// Move fully deactivated stake for one validator back into the vault buffer.
+/// The stake program rejects the withdrawal while the cooldown is running.
+pub fn handler(ctx: Context<WithdrawDeactivatedStake>) -> Result<()> {
+    let clock = Clock::get()?;
+    let validator = ctx.accounts.validator_vote_account.key();
+    let stake_account = ctx.accounts.stake_account.to_account_info();
+    let transient_stake_account = ctx.accounts.transient_stake_account.to_account_info();
+
+    let mut validator_list = ctx.accounts.validator_list.load_mut()?;
+    let entry = validator_list
+        .find_mut(&validator)
+        .ok_or(ErrorCode::ValidatorNotFound)?;
+
+    let mut withdrawn: u64 = 0;
+
+    if transient_stake_account.owner == &stake::program::ID
+        && read_stake_delegation(&transient_stake_account)?.deactivation_epoch != u64::MAX
+    {
+        withdrawn = ctx.accounts.withdraw_all(&transient_stake_account)?;
+        entry.transient_lamports = 0;
+    }
+
+    let removed = entry.status() == ValidatorStatus::Deactivating
+        && stake_account.owner == &stake::program::ID;
+    if removed {
+        withdrawn = withdrawn
+            .checked_add(ctx.accounts.withdraw_all(&stake_account)?)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        entry.active_lamports = 0;
+        entry.set_status(ValidatorStatus::Removed);
+    }
+
+    require!(withdrawn > 0, ErrorCode::InvalidStakeState);
+    entry.last_update_epoch = clock.epoch;
+    drop(validator_list);
+
+    let vault = &mut ctx.accounts.vault;
+
+    // Total assets are unchanged: gains and losses since the last balance
+    // update are booked by the fee-charging paths, not here
+    vault.book_stake_withdrawal(withdrawn)?;
+
+    if removed {
+        vault.active_validators = vault
+            .active_validators
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    }
+
+    emit!(StakeWithdrawn {
+        vault: vault.key(),
+        validator,
+        amount: withdrawn,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault {} withdrew {} SOL of deactivated stake from validator {}",
+        vault.vault_id,
+        withdrawn as f64 / 1e9,
+        validator
+    );
+
+    Ok(())
+}
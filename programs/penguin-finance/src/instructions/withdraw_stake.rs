@@ -0,0 +1,238 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::stake::{
+    self,
+    state::{StakeAuthorize, StakeStateV2},
+};
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token_2022::{burn, Burn, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = vault_token_mint,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VALIDATOR_LIST_SEED, vault.key().as_ref()],
+        bump = validator_list.load()?.bump,
+    )]
+    pub validator_list: AccountLoader<'info, ValidatorList>,
+
+    /// CHECK: Validator vote account, only used for PDA derivation
+    pub validator_vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: Vault stake account PDA for this validator
+    #[account(
+        mut,
+        seeds = [
+            STAKE_ACCOUNT_SEED,
+            vault.key().as_ref(),
+            validator_vote_account.key().as_ref()
+        ],
+        bump,
+        owner = stake::program::ID @ ErrorCode::StakeAccountNotFound,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// New stake account receiving the split, fresh keypair handed over to the user
+    #[account(mut)]
+    pub user_stake_account: Signer<'info>,
+
+    /// User's vault token account
+    #[account(
+        mut,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+
+    /// CHECK: Stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> WithdrawStake<'info> {
+    fn authorize(&self, authorize: StakeAuthorize, vault_seeds: &[&[u8]]) -> Result<()> {
+        invoke_signed(
+            &stake::instruction::authorize(
+                self.user_stake_account.key,
+                &self.vault.key(),
+                self.user.key,
+                authorize,
+                None,
+            ),
+            &[
+                self.user_stake_account.to_account_info(),
+                self.clock.to_account_info(),
+                self.vault.to_account_info(),
+            ],
+            &[vault_seeds],
+        )?;
+        Ok(())
+    }
+}
+
+/// Burns vault tokens and splits their SOL value off the validator's stake
+/// account into a stake account the user controls. The user pays the new
+/// account's rent-exempt reserve and can deactivate it on their own schedule
+pub fn handler(ctx: Context<WithdrawStake>, vault_token_amount: u64) -> Result<()> {
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_REQUESTS)?;
+    require!(vault_token_amount > 0, ErrorCode::InvalidCollateralAmount);
+    // The exchange rate is not trusted while a balance update awaits review
+    require!(!ctx.accounts.vault.quarantined, ErrorCode::QuarantinePending);
+
+    let vault = &ctx.accounts.vault;
+    let vault_key = vault.key();
+    let validator = ctx.accounts.validator_vote_account.key();
+    let clock = Clock::get()?;
+
+    let sol_amount = vault.shares_to_sol(vault_token_amount)?;
+    require!(sol_amount >= MIN_STAKE_AMOUNT, ErrorCode::StakeTooSmall);
+
+    // The vault's stake account must stay above the minimum delegation
+    let rent = Rent::get()?.minimum_balance(StakeStateV2::size_of());
+    let min_remaining = rent
+        .checked_add(MIN_STAKE_AMOUNT)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let remaining = ctx
+        .accounts
+        .stake_account
+        .lamports()
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::InsufficientVaultBalance)?;
+    require!(remaining >= min_remaining, ErrorCode::StakeTooSmall);
+
+    let mut validator_list = ctx.accounts.validator_list.load_mut()?;
+    let entry = validator_list
+        .find_mut(&validator)
+        .ok_or(ErrorCode::ValidatorNotFound)?;
+    require!(
+        entry.status() == ValidatorStatus::Active,
+        ErrorCode::InvalidStakeState
+    );
+    let active_lamports = entry
+        .active_lamports
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    // Burn vault tokens
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
+            from: ctx.accounts.user_vault_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+    );
+    burn(burn_ctx, vault_token_amount)?;
+
+    // Split destinations must already hold their rent-exempt reserve
+    invoke(
+        &system_instruction::transfer(
+            ctx.accounts.user.key,
+            ctx.accounts.user_stake_account.key,
+            rent,
+        ),
+        &[
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.user_stake_account.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+    for instruction in stake::instruction::split(
+        ctx.accounts.stake_account.key,
+        &vault_key,
+        sol_amount,
+        ctx.accounts.user_stake_account.key,
+    ) {
+        invoke_signed(
+            &instruction,
+            &[
+                ctx.accounts.stake_account.to_account_info(),
+                ctx.accounts.user_stake_account.to_account_info(),
+                vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&vault_seeds[..]],
+        )?;
+    }
+
+    // Hand both authorities over to the user
+    ctx.accounts.authorize(StakeAuthorize::Staker, vault_seeds)?;
+    ctx.accounts.authorize(StakeAuthorize::Withdrawer, vault_seeds)?;
+
+    entry.active_lamports = active_lamports;
+    entry.last_update_epoch = clock.epoch;
+    drop(validator_list);
+
+    // Update vault state
+    let vault = &mut ctx.accounts.vault;
+    vault.total_shares = vault
+        .total_shares
+        .checked_sub(vault_token_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    vault.total_assets = vault
+        .total_assets
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    vault.total_staked = vault
+        .total_staked
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    emit!(StakeAccountWithdrawn {
+        vault: vault_key,
+        user: ctx.accounts.user.key(),
+        validator,
+        stake_account: ctx.accounts.user_stake_account.key(),
+        vault_tokens_burned: vault_token_amount,
+        sol_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("User {} withdrew stake from vault {}", ctx.accounts.user.key(), vault.vault_id);
+    msg!("Vault tokens burned: {}", vault_token_amount as f64 / 1e9);
+    msg!("Stake: {} SOL with validator {}", sol_amount as f64 / 1e9, validator);
+
+    Ok(())
+}
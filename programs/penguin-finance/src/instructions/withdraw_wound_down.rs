@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{burn, Burn, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use crate::utils::transfer_from_reserve;
+
+#[derive(Accounts)]
+pub struct WithdrawWoundDown<'info> {
+    #[account(
+        seeds = [FACTORY_SEED],
+        bump = factory.bump,
+    )]
+    pub factory: Account<'info, Factory>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.factory.as_ref(), &vault.vault_id.to_le_bytes()],
+        bump = vault.bump,
+        has_one = factory,
+        has_one = vault_token_mint,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_RESERVE_SEED, vault.key().as_ref()],
+        bump = vault.reserve_bump,
+    )]
+    pub vault_reserve: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub vault_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// User's vault token account
+    #[account(
+        mut,
+        associated_token::mint = vault_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Redeem vault tokens for their pro rata share of a fully unstaked vault,
+/// without going through withdrawal tickets
+pub fn handler(ctx: Context<WithdrawWoundDown>, vault_token_amount: u64) -> Result<()> {
+    ctx.accounts
+        .factory
+        .require_not_paused(ctx.accounts.vault.pause_flags, PAUSE_WITHDRAWAL_CLAIMS)?;
+    require!(vault_token_amount > 0, ErrorCode::InvalidCollateralAmount);
+    require!(
+        ctx.accounts.vault.status == VaultStatus::WindingDown,
+        ErrorCode::InvalidVaultStatus
+    );
+    require!(ctx.accounts.vault.total_staked == 0, ErrorCode::VaultStillStaked);
+
+    let vault = &mut ctx.accounts.vault;
+    let clock = Clock::get()?;
+
+    let sol_amount = vault.shares_to_sol(vault_token_amount)?;
+    require!(
+        vault.free_buffer() >= sol_amount,
+        ErrorCode::InsufficientBufferedSol
+    );
+
+    // Burn vault tokens
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.vault_token_mint.to_account_info(),
+            from: ctx.accounts.user_vault_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+    );
+    burn(burn_ctx, vault_token_amount)?;
+
+    // Update vault state
+    vault.total_shares = vault
+        .total_shares
+        .checked_sub(vault_token_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    vault.total_assets = vault
+        .total_assets
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    vault.buffered_sol = vault
+        .buffered_sol
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    transfer_from_reserve(
+        vault,
+        &ctx.accounts.vault_reserve.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program,
+        sol_amount,
+    )?;
+
+    emit!(WindDownWithdrawal {
+        vault: vault.key(),
+        user: ctx.accounts.user.key(),
+        vault_tokens_burned: vault_token_amount,
+        sol_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("User {} withdrew {} SOL from winding-down vault {}",
+        ctx.accounts.user.key(),
+        sol_amount as f64 / 1e9,
+        vault.vault_id
+    );
+
+    Ok(())
+}
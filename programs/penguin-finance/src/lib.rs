@@ -6,8 +6,10 @@ pub mod errors;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use instructions::*;
+use state::{ProtocolParam, Role};
 
 declare_id!("GQtLSrEgqfhcETMzQcP4dX2Sgv3WEnxDnCcZCZg6a9m4");
 
@@ -43,12 +45,60 @@ pub mod penguin_finance {
         instructions::stake_from_vault::handler(ctx, amount)
     }
 
-    /// Update vault balance and distribute rewards
-    pub fn update_vault_balance(
-        ctx: Context<UpdateVaultBalance>,
-        new_total_staked: u64,
+    /// Merge a transient stake top-up into the validator's stake account
+    pub fn merge_transient_stake(ctx: Context<MergeTransientStake>) -> Result<()> {
+        instructions::merge_transient_stake::handler(ctx)
+    }
+
+    /// Deactivate all or part of a validator's stake
+    pub fn deactivate_validator_stake(
+        ctx: Context<DeactivateValidatorStake>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deactivate_validator_stake::handler(ctx, amount)
+    }
+
+    /// Withdraw deactivated stake back into the vault buffer
+    pub fn withdraw_deactivated_stake(ctx: Context<WithdrawDeactivatedStake>) -> Result<()> {
+        instructions::withdraw_deactivated_stake::handler(ctx)
+    }
+
+    /// Fallback: apply the finalized oracle balance report and distribute rewards
+    pub fn update_vault_balance(ctx: Context<UpdateVaultBalance>) -> Result<()> {
+        instructions::update_vault_balance::handler(ctx)
+    }
+
+    /// Approve or reject a balance update held back by the circuit breaker (risk manager)
+    pub fn resolve_quarantined_update(
+        ctx: Context<ResolveQuarantinedUpdate>,
+        approve: bool,
     ) -> Result<()> {
-        instructions::update_vault_balance::handler(ctx, new_total_staked)
+        instructions::resolve_quarantined_update::handler(ctx, approve)
+    }
+
+    /// Report a vault's total staked balance for the current epoch (oracle reporter)
+    pub fn submit_balance_report(
+        ctx: Context<SubmitBalanceReport>,
+        total_staked: u64,
+    ) -> Result<()> {
+        instructions::submit_balance_report::handler(ctx, total_staked)
+    }
+
+    /// Deposit an active stake account into a vault
+    pub fn deposit_stake(ctx: Context<DepositStake>) -> Result<()> {
+        instructions::deposit_stake::handler(ctx)
+    }
+
+    /// Recompute the vault balance from its stake accounts and distribute rewards (authoritative)
+    pub fn crank_vault_balance<'info>(
+        ctx: Context<'_, '_, '_, 'info, CrankVaultBalance<'info>>,
+    ) -> Result<()> {
+        instructions::crank_vault_balance::handler(ctx)
+    }
+
+    /// Book SOL sent straight to a vault reserve as rewards, or flag a shortfall
+    pub fn sync_vault_reserve(ctx: Context<SyncVaultReserve>) -> Result<()> {
+        instructions::sync_vault_reserve::handler(ctx)
     }
 
     /// Mint pSOL using vault tokens as collateral
@@ -73,13 +123,187 @@ pub mod penguin_finance {
         instructions::request_withdrawal::handler(ctx, vault_token_amount)
     }
 
+    /// Reserve buffered SOL for queued withdrawal tickets in request order
+    pub fn fulfill_withdrawals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FulfillWithdrawals<'info>>,
+    ) -> Result<()> {
+        instructions::fulfill_withdrawals::handler(ctx)
+    }
+
+    /// Cancel an unclaimed withdrawal ticket and get vault tokens back
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::cancel_withdrawal::handler(ctx)
+    }
+
+    /// Hand a withdrawal ticket over to a new owner
+    pub fn transfer_withdrawal_ticket(ctx: Context<TransferWithdrawalTicket>) -> Result<()> {
+        instructions::transfer_withdrawal_ticket::handler(ctx)
+    }
+
+    /// Burn vault tokens for a split of a vault stake account
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>, vault_token_amount: u64) -> Result<()> {
+        instructions::withdraw_stake::handler(ctx, vault_token_amount)
+    }
+
     /// Claim completed withdrawal
     pub fn claim_withdrawal(ctx: Context<ClaimWithdrawal>) -> Result<()> {
         instructions::claim_withdrawal::handler(ctx)
     }
 
+    /// Claim several fulfilled withdrawal tickets of one user
+    pub fn claim_withdrawals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimWithdrawals<'info>>,
+    ) -> Result<()> {
+        instructions::claim_withdrawals::handler(ctx)
+    }
+
+    /// Create an instant unstake liquidity pool for a vault (operator)
+    pub fn create_liquidity_pool(
+        ctx: Context<CreateLiquidityPool>,
+        min_fee_bps: u16,
+        max_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::create_liquidity_pool::handler(ctx, min_fee_bps, max_fee_bps)
+    }
+
+    /// Deposit SOL into a liquidity pool for LP tokens
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+        instructions::add_liquidity::handler(ctx, amount)
+    }
+
+    /// Burn LP tokens for their share of the pool's liquid SOL
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, lp_amount: u64) -> Result<()> {
+        instructions::remove_liquidity::handler(ctx, lp_amount)
+    }
+
+    /// Sell vault tokens to the liquidity pool for SOL right away
+    pub fn instant_unstake(
+        ctx: Context<InstantUnstake>,
+        vault_token_amount: u64,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        instructions::instant_unstake::handler(ctx, vault_token_amount, min_sol_out)
+    }
+
+    /// Settle a fulfilled withdrawal ticket held by a liquidity pool
+    pub fn claim_pool_redemption(ctx: Context<ClaimPoolRedemption>) -> Result<()> {
+        instructions::claim_pool_redemption::handler(ctx)
+    }
+
     /// Liquidate unhealthy pSOL position
     pub fn liquidate_position(ctx: Context<LiquidatePosition>) -> Result<()> {
         instructions::liquidate_position::handler(ctx)
     }
-}
\ No newline at end of file
+
+    /// Pause protocol-wide operation categories (`PAUSE_*` bits)
+    pub fn pause_protocol(ctx: Context<PauseProtocol>, flags: u8) -> Result<()> {
+        instructions::pause_protocol::handler(ctx, flags)
+    }
+
+    /// Unpause protocol-wide operation categories (`PAUSE_*` bits)
+    pub fn unpause_protocol(ctx: Context<UnpauseProtocol>, flags: u8) -> Result<()> {
+        instructions::unpause_protocol::handler(ctx, flags)
+    }
+
+    /// Pause operation categories for a single vault (`PAUSE_*` bits)
+    pub fn pause_vault(ctx: Context<PauseVault>, flags: u8) -> Result<()> {
+        instructions::pause_vault::handler(ctx, flags)
+    }
+
+    /// Unpause operation categories for a single vault (`PAUSE_*` bits)
+    pub fn unpause_vault(ctx: Context<UnpauseVault>, flags: u8) -> Result<()> {
+        instructions::unpause_vault::handler(ctx, flags)
+    }
+
+    /// Rotate the protocol treasury
+    pub fn update_treasury(ctx: Context<UpdateTreasury>) -> Result<()> {
+        instructions::update_treasury::handler(ctx)
+    }
+
+    /// Propose a new protocol authority
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a pending protocol authority handover
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Queue a timelocked protocol parameter change
+    pub fn queue_param_change(
+        ctx: Context<QueueParamChange>,
+        param: ProtocolParam,
+        new_value: u64,
+    ) -> Result<()> {
+        instructions::queue_param_change::handler(ctx, param, new_value)
+    }
+
+    /// Execute a queued parameter change after its timelock
+    pub fn execute_param_change(ctx: Context<ExecuteParamChange>) -> Result<()> {
+        instructions::execute_param_change::handler(ctx)
+    }
+
+    /// Cancel a queued parameter change
+    pub fn cancel_param_change(ctx: Context<CancelParamChange>) -> Result<()> {
+        instructions::cancel_param_change::handler(ctx)
+    }
+
+    /// Grant an operational role
+    pub fn grant_role(ctx: Context<GrantRole>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::grant_role::handler(ctx, member, role)
+    }
+
+    /// Revoke an operational role
+    pub fn revoke_role(ctx: Context<RevokeRole>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::revoke_role::handler(ctx, member, role)
+    }
+
+    /// Open or close a vault for deposits
+    pub fn set_accepting_deposits(
+        ctx: Context<SetAcceptingDeposits>,
+        accepting_deposits: bool,
+    ) -> Result<()> {
+        instructions::set_accepting_deposits::handler(ctx, accepting_deposits)
+    }
+
+    /// Update a vault's maximum SOL capacity
+    pub fn update_max_capacity(ctx: Context<UpdateMaxCapacity>, new_max_capacity: u64) -> Result<()> {
+        instructions::update_max_capacity::handler(ctx, new_max_capacity)
+    }
+
+    /// Rename a vault
+    pub fn rename_vault(ctx: Context<RenameVault>, vault_name: String) -> Result<()> {
+        instructions::rename_vault::handler(ctx, vault_name)
+    }
+
+    /// Update a vault's operator fee
+    pub fn update_vault_fee(ctx: Context<UpdateVaultFee>, new_fee_basis_points: u16) -> Result<()> {
+        instructions::update_vault_fee::handler(ctx, new_fee_basis_points)
+    }
+
+    /// Propose a new vault operator
+    pub fn propose_operator(ctx: Context<ProposeOperator>, new_operator: Pubkey) -> Result<()> {
+        instructions::propose_operator::handler(ctx, new_operator)
+    }
+
+    /// Accept a pending vault operator handover
+    pub fn accept_operator(ctx: Context<AcceptOperator>) -> Result<()> {
+        instructions::accept_operator::handler(ctx)
+    }
+
+    /// Start winding down a vault
+    pub fn start_wind_down(ctx: Context<StartWindDown>) -> Result<()> {
+        instructions::start_wind_down::handler(ctx)
+    }
+
+    /// Withdraw pro rata from a fully unstaked winding-down vault
+    pub fn withdraw_wound_down(ctx: Context<WithdrawWoundDown>, vault_token_amount: u64) -> Result<()> {
+        instructions::withdraw_wound_down::handler(ctx, vault_token_amount)
+    }
+
+    /// Close a fully redeemed winding-down vault
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        instructions::close_vault::handler(ctx)
+    }
+}
@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+
+/// A single reporter's view of a vault balance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OracleReport {
+    /// Reporter holding `Role::OracleReporter`
+    pub reporter: Pubkey,
+    
+    /// Reported total staked (in lamports)
+    pub total_staked: u64,
+    
+    /// Set on finalization if the report was outside the tolerance
+    pub deviant: bool,
+}
+
+impl OracleReport {
+    pub const LEN: usize = 32 + // reporter
+        8 +  // total_staked
+        1;   // deviant
+}
+
+/// Oracle reports for one vault and epoch, finalized at the median once
+/// the quorum is reached
+#[account]
+#[derive(Default)]
+pub struct BalanceReport {
+    /// Reference to vault
+    pub vault: Pubkey,
+    
+    /// Epoch the reports are for
+    pub epoch: u64,
+    
+    /// Reports needed to finalize, fixed when the first report arrives
+    pub quorum: u8,
+    
+    /// Allowed deviation from the median, fixed when the first report arrives
+    pub tolerance_bps: u16,
+    
+    /// Submitted reports
+    pub reports: Vec<OracleReport>,
+    
+    /// Whether the quorum was reached
+    pub finalized: bool,
+    
+    /// Median of the reports once finalized
+    pub median_total_staked: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl BalanceReport {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // vault
+        8 +  // epoch
+        1 +  // quorum
+        2 +  // tolerance_bps
+        4 + MAX_ORACLE_REPORTS * OracleReport::LEN + // reports
+        1 +  // finalized
+        8 +  // median_total_staked
+        1;   // bump
+
+    /// Add a report, finalizing once the quorum is reached.
+    /// Returns true if this report finalized the balance
+    pub fn submit(&mut self, reporter: Pubkey, total_staked: u64) -> Result<bool> {
+        require!(!self.finalized, ErrorCode::BalanceReportFinalized);
+        require!(
+            !self.reports.iter().any(|r| r.reporter == reporter),
+            ErrorCode::DuplicateReport
+        );
+
+        self.reports.push(OracleReport {
+            reporter,
+            total_staked,
+            deviant: false,
+        });
+
+        if self.reports.len() < self.quorum as usize {
+            return Ok(false);
+        }
+
+        self.finalize()?;
+        Ok(true)
+    }
+
+    /// Take the median and flag reports outside the tolerance
+    fn finalize(&mut self) -> Result<()> {
+        let mut values: Vec<u64> = self.reports.iter().map(|r| r.total_staked).collect();
+        values.sort_unstable();
+
+        let mid = values.len() / 2;
+        let median = if values.len() == mid * 2 {
+            // Average without overflowing
+            values[mid - 1] + (values[mid] - values[mid - 1]) / 2
+        } else {
+            values[mid]
+        };
+
+        let max_deviation = (median as u128)
+            .checked_mul(self.tolerance_bps as u128)
+            .map(|v| v / BASIS_POINTS_DIVISOR as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        for report in self.reports.iter_mut() {
+            report.deviant = (report.total_staked.abs_diff(median) as u128) > max_deviation;
+        }
+
+        self.median_total_staked = median;
+        self.finalized = true;
+        Ok(())
+    }
+
+    /// Reports flagged as outside the tolerance
+    pub fn deviants(&self) -> impl Iterator<Item = &OracleReport> {
+        self.reports.iter().filter(|r| r.deviant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(quorum: u8, tolerance_bps: u16) -> BalanceReport {
+        BalanceReport {
+            quorum,
+            tolerance_bps,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn finalizes_at_quorum_with_odd_median() {
+        let mut r = report(3, 50);
+
+        assert!(!r.submit(Pubkey::new_unique(), 1_000).unwrap());
+        assert!(!r.submit(Pubkey::new_unique(), 3_000).unwrap());
+        assert!(r.submit(Pubkey::new_unique(), 2_000).unwrap());
+
+        assert!(r.finalized);
+        assert_eq!(r.median_total_staked, 2_000);
+    }
+
+    #[test]
+    fn even_count_median_averages_middle_reports() {
+        let mut r = report(4, 50);
+
+        for total_staked in [4_000, 1_000, 3_001, 2_000] {
+            r.submit(Pubkey::new_unique(), total_staked).unwrap();
+        }
+
+        // (2_000 + 3_001) / 2, rounded down
+        assert_eq!(r.median_total_staked, 2_500);
+    }
+
+    #[test]
+    fn even_count_median_does_not_overflow() {
+        let mut r = report(2, 0);
+
+        r.submit(Pubkey::new_unique(), u64::MAX).unwrap();
+        r.submit(Pubkey::new_unique(), u64::MAX - 2).unwrap();
+
+        assert_eq!(r.median_total_staked, u64::MAX - 1);
+    }
+
+    #[test]
+    fn flags_reports_outside_tolerance() {
+        // 1% of the 10_050 median, rounded down to 100
+        let mut r = report(3, 100);
+
+        let outlier = Pubkey::new_unique();
+        r.submit(Pubkey::new_unique(), 10_000).unwrap();
+        r.submit(Pubkey::new_unique(), 10_050).unwrap();
+        r.submit(outlier, 10_200).unwrap();
+
+        let deviants: Vec<_> = r.deviants().map(|d| d.reporter).collect();
+        assert_eq!(deviants, vec![outlier]);
+    }
+
+    #[test]
+    fn rejects_duplicate_and_late_reports() {
+        let mut r = report(2, 50);
+        let reporter = Pubkey::new_unique();
+
+        r.submit(reporter, 1_000).unwrap();
+        assert_eq!(
+            r.submit(reporter, 1_000).unwrap_err(),
+            ErrorCode::DuplicateReport.into()
+        );
+
+        r.submit(Pubkey::new_unique(), 1_000).unwrap();
+        assert_eq!(
+            r.submit(Pubkey::new_unique(), 1_000).unwrap_err(),
+            ErrorCode::BalanceReportFinalized.into()
+        );
+    }
+}
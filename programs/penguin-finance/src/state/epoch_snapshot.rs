@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{RewardDistribution, Vault};
+
+/// Reward accounting of one vault balance update, one per vault per epoch
+#[account]
+pub struct EpochSnapshot {
+    /// Reference to vault
+    pub vault: Pubkey,
+    
+    /// Epoch the update ran in
+    pub epoch: u64,
+    
+    /// Epochs since the previous update, more than 1 for a catch-up
+    pub epochs_covered: u64,
+    
+    /// Total assets before the update
+    pub starting_total_assets: u64,
+    
+    /// Total shares before the update
+    pub starting_total_shares: u64,
+    
+    /// Exchange rate before the update (scaled by 1e9)
+    pub starting_exchange_rate: u64,
+    
+    /// Rewards booked over the covered epochs
+    pub rewards: u64,
+    
+    /// Loss booked over the covered epochs
+    pub loss: u64,
+    
+    /// Protocol fee charged (in SOL)
+    pub protocol_fee: u64,
+    
+    /// Operator fee charged (in SOL)
+    pub operator_fee: u64,
+    
+    /// Exchange rate after the update (scaled by 1e9)
+    pub exchange_rate: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl EpochSnapshot {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // vault
+        8 +  // epoch
+        8 +  // epochs_covered
+        8 +  // starting_total_assets
+        8 +  // starting_total_shares
+        8 +  // starting_exchange_rate
+        8 +  // rewards
+        8 +  // loss
+        8 +  // protocol_fee
+        8 +  // operator_fee
+        8 +  // exchange_rate
+        1;   // bump
+
+    /// Record the vault state before rewards are distributed
+    pub fn record_start(&mut self, vault: &Account<Vault>, epoch: u64, bump: u8) -> Result<()> {
+        self.vault = vault.key();
+        self.epoch = epoch;
+        self.epochs_covered = epoch
+            .checked_sub(vault.last_reward_epoch)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        self.starting_total_assets = vault.total_assets;
+        self.starting_total_shares = vault.total_shares;
+        self.starting_exchange_rate = vault.exchange_rate()?;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Record the outcome of the distribution
+    pub fn record_distribution(&mut self, distribution: &RewardDistribution, exchange_rate: u64) {
+        self.rewards = distribution.rewards;
+        self.loss = distribution.loss;
+        self.protocol_fee = distribution.protocol_fee;
+        self.operator_fee = distribution.operator_fee;
+        self.exchange_rate = exchange_rate;
+    }
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+/// Vault state recorded at one balance update
+#[zero_copy]
+pub struct RateHistoryEntry {
+    /// Epoch of the balance update
+    pub epoch: u64,
+    
+    /// Total assets after the update
+    pub total_assets: u64,
+    
+    /// Total shares after the update
+    pub total_shares: u64,
+    
+    /// Exchange rate after the update (scaled by 1e9)
+    pub exchange_rate: u64,
+    
+    /// Rewards booked by the update
+    pub rewards: u64,
+}
+
+impl RateHistoryEntry {
+    pub const LEN: usize = 8 + // epoch
+        8 + // total_assets
+        8 + // total_shares
+        8 + // exchange_rate
+        8;  // rewards
+}
+
+/// Ring buffer of the last `RATE_HISTORY_LEN` balance updates of a vault
+#[account(zero_copy)]
+pub struct ExchangeRateHistory {
+    /// Vault owning this history
+    pub vault: Pubkey,
+    
+    /// Index the next entry is written to
+    pub head: u32,
+    
+    /// Number of entries in use
+    pub len: u32,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+    
+    pub _padding: [u8; 7],
+    
+    /// Entries, oldest overwritten first
+    pub entries: [RateHistoryEntry; RATE_HISTORY_LEN],
+}
+
+impl ExchangeRateHistory {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // vault
+        4 +  // head
+        4 +  // len
+        1 +  // bump
+        7 +  // padding
+        RATE_HISTORY_LEN * RateHistoryEntry::LEN; // entries
+
+    /// Append an entry, overwriting the oldest once full
+    pub fn push(&mut self, entry: RateHistoryEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = (self.head + 1) % RATE_HISTORY_LEN as u32;
+        self.len = (self.len + 1).min(RATE_HISTORY_LEN as u32);
+    }
+
+    /// Entries from newest to oldest
+    pub fn iter_recent(&self) -> impl Iterator<Item = &RateHistoryEntry> {
+        let head = self.head as usize;
+        (1..=self.len as usize)
+            .map(move |i| &self.entries[(head + RATE_HISTORY_LEN - i) % RATE_HISTORY_LEN])
+    }
+
+    /// Most recent entry
+    pub fn latest(&self) -> Option<&RateHistoryEntry> {
+        self.iter_recent().next()
+    }
+
+    /// Exchange rate averaged over the last `window_epochs`, each rate
+    /// weighted by the epochs it was in effect. `None` without history
+    pub fn twap(&self, current_epoch: u64, window_epochs: u64) -> Option<u64> {
+        let latest = self.latest()?;
+        let window_start = current_epoch.saturating_sub(window_epochs);
+
+        // The latest rate counts for at least one epoch
+        let mut end = current_epoch.max(latest.epoch + 1);
+        let mut weighted: u128 = 0;
+        let mut total_weight: u128 = 0;
+
+        for entry in self.iter_recent() {
+            let start = entry.epoch.max(window_start);
+            if end > start {
+                let weight = (end - start) as u128;
+                weighted += entry.exchange_rate as u128 * weight;
+                total_weight += weight;
+            }
+            if entry.epoch <= window_start {
+                break;
+            }
+            end = entry.epoch;
+        }
+
+        if total_weight == 0 {
+            return None;
+        }
+        u64::try_from(weighted / total_weight).ok()
+    }
+
+    /// Annualized exchange-rate growth over the last `window_epochs`, in
+    /// basis points (negative after losses). `None` without two entries
+    pub fn trailing_apy_bps(&self, current_epoch: u64, window_epochs: u64) -> Option<i64> {
+        let latest = self.latest()?;
+        let window_start = current_epoch.saturating_sub(window_epochs);
+        let oldest = self
+            .iter_recent()
+            .skip(1)
+            .take_while(|entry| entry.epoch >= window_start)
+            .last()?;
+
+        let epochs = latest.epoch.checked_sub(oldest.epoch).filter(|e| *e > 0)? as i128;
+        let old_rate = oldest.exchange_rate as i128;
+        if old_rate == 0 {
+            return None;
+        }
+
+        let growth_bps = (latest.exchange_rate as i128 - old_rate) * BASIS_POINTS_DIVISOR as i128 / old_rate;
+        i64::try_from(growth_bps * EPOCHS_PER_YEAR as i128 / epochs).ok()
+    }
+
+    /// Rate to value collateral at: the lower of spot and TWAP, or spot
+    /// alone when the window is 0 or there is no history yet
+    pub fn valuation_rate(&self, spot_rate: u64, current_epoch: u64, window_epochs: u64) -> u64 {
+        if window_epochs == 0 {
+            return spot_rate;
+        }
+        self.twap(current_epoch, window_epochs)
+            .map_or(spot_rate, |twap| twap.min(spot_rate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u64 = 1_000_000_000;
+
+    fn history(entries: &[(u64, u64)]) -> Box<ExchangeRateHistory> {
+        let mut history: Box<ExchangeRateHistory> = Box::new(bytemuck::Zeroable::zeroed());
+        for &(epoch, exchange_rate) in entries {
+            history.push(RateHistoryEntry {
+                epoch,
+                exchange_rate,
+                ..bytemuck::Zeroable::zeroed()
+            });
+        }
+        history
+    }
+
+    #[test]
+    fn twap_weights_rates_across_epoch_gaps() {
+        let h = history(&[(10, RATE), (15, 1_100_000_000)]);
+
+        // Each rate holds until the next update: 5 epochs at 1.0, 5 at 1.1
+        assert_eq!(h.twap(20, 20), Some(1_050_000_000));
+    }
+
+    #[test]
+    fn twap_clips_to_window() {
+        let h = history(&[(10, RATE), (15, 1_100_000_000)]);
+
+        // Window starts at 12: 3 epochs at 1.0, 5 at 1.1
+        assert_eq!(h.twap(20, 8), Some(1_062_500_000));
+    }
+
+    #[test]
+    fn twap_counts_latest_rate_for_at_least_one_epoch() {
+        let h = history(&[(10, RATE), (20, 1_100_000_000)]);
+
+        // 10 epochs at 1.0, then 1 at 1.1
+        assert_eq!(h.twap(20, 20), Some(1_009_090_909));
+        assert_eq!(history(&[]).twap(20, 20), None);
+    }
+
+    #[test]
+    fn ring_buffer_keeps_latest_entries() {
+        let entries: Vec<_> = (0..RATE_HISTORY_LEN as u64 + 3).map(|e| (e, RATE + e)).collect();
+        let h = history(&entries);
+
+        assert_eq!(h.len as usize, RATE_HISTORY_LEN);
+        assert_eq!(h.latest().unwrap().epoch, RATE_HISTORY_LEN as u64 + 2);
+        assert_eq!(h.iter_recent().last().unwrap().epoch, 3);
+    }
+
+    #[test]
+    fn trailing_apy_annualizes_growth() {
+        // Half a year at +5% and -5%
+        let up = history(&[(0, RATE), (91, 1_050_000_000)]);
+        let down = history(&[(0, RATE), (91, 950_000_000)]);
+
+        assert_eq!(up.trailing_apy_bps(91, 91), Some(1000));
+        assert_eq!(down.trailing_apy_bps(91, 91), Some(-1000));
+        assert_eq!(history(&[(0, RATE)]).trailing_apy_bps(91, 91), None);
+    }
+
+    #[test]
+    fn valuation_rate_takes_lower_of_spot_and_twap() {
+        let h = history(&[(10, RATE), (15, 1_100_000_000)]);
+
+        assert_eq!(h.valuation_rate(1_100_000_000, 20, 20), 1_050_000_000);
+        assert_eq!(h.valuation_rate(900_000_000, 20, 20), 900_000_000);
+        assert_eq!(h.valuation_rate(1_100_000_000, 20, 0), 1_100_000_000);
+    }
+}
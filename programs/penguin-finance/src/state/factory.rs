@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::TimelockUnit;
+
 #[account]
 pub struct Factory {
     /// Authority that can update protocol parameters
     pub authority: Pubkey,
     
+    /// Proposed new authority awaiting acceptance (default if none)
+    pub pending_authority: Pubkey,
+    
     /// Protocol treasury for collecting fees
     pub treasury: Pubkey,
     
@@ -14,8 +21,8 @@ pub struct Factory {
     /// Protocol fee in basis points (e.g., 100 = 1%)
     pub protocol_fee_bps: u16,
     
-    /// Whether protocol is paused
-    pub paused: bool,
+    /// Protocol-wide pause flags (`PAUSE_*` bits)
+    pub pause_flags: u8,
     
     /// pSOL mint address
     pub psol_mint: Pubkey,
@@ -23,6 +30,27 @@ pub struct Factory {
     /// pSOL controller address
     pub psol_controller: Pubkey,
     
+    /// Unit the parameter-change timelock is measured in
+    pub timelock_unit: TimelockUnit,
+    
+    /// Delay before a queued parameter change can be executed
+    pub timelock_delay: u64,
+    
+    /// Total number of parameter changes queued
+    pub param_change_count: u64,
+    
+    /// Oracle reports needed to finalize a vault balance
+    pub oracle_quorum: u8,
+    
+    /// Allowed deviation of a report from the median, in basis points
+    pub oracle_tolerance_bps: u16,
+    
+    /// Highest implied APR a balance update may book, in basis points
+    pub max_reward_apr_bps: u16,
+    
+    /// Largest exchange-rate move a balance update may book, in basis points
+    pub max_rate_jump_bps: u16,
+    
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -30,11 +58,39 @@ pub struct Factory {
 impl Factory {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        32 + // pending_authority
         32 + // treasury
         8 +  // vault_count
         2 +  // protocol_fee_bps
-        1 +  // paused
+        1 +  // pause_flags
         32 + // psol_mint
         32 + // psol_controller
+        1 +  // timelock_unit
+        8 +  // timelock_delay
+        8 +  // param_change_count
+        1 +  // oracle_quorum
+        2 +  // oracle_tolerance_bps
+        2 +  // max_reward_apr_bps
+        2 +  // max_rate_jump_bps
         1;   // bump
-}
\ No newline at end of file
+
+    /// Require that an operation category is paused neither protocol-wide
+    /// nor by the vault's own override flags
+    pub fn require_not_paused(&self, vault_pause_flags: u8, flag: u8) -> Result<()> {
+        if (self.pause_flags | vault_pause_flags) & flag == 0 {
+            return Ok(());
+        }
+
+        let error = match flag {
+            PAUSE_DEPOSITS => ErrorCode::DepositsPaused,
+            PAUSE_STAKING => ErrorCode::StakingPaused,
+            PAUSE_MINTING => ErrorCode::MintingPaused,
+            PAUSE_BURNING => ErrorCode::BurningPaused,
+            PAUSE_WITHDRAWAL_REQUESTS => ErrorCode::WithdrawalRequestsPaused,
+            PAUSE_WITHDRAWAL_CLAIMS => ErrorCode::WithdrawalClaimsPaused,
+            PAUSE_LIQUIDATIONS => ErrorCode::LiquidationsPaused,
+            _ => ErrorCode::VaultPaused,
+        };
+        Err(error.into())
+    }
+}
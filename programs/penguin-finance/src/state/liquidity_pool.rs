@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+
+/// Per-vault pool that buys vault tokens for SOL right away and redeems
+/// them through the withdrawal queue. SOL is held in the pool account itself
+#[account]
+#[derive(Default)]
+pub struct LiquidityPool {
+    /// Vault whose tokens the pool buys
+    pub vault: Pubkey,
+    
+    /// LP token mint
+    pub lp_mint: Pubkey,
+    
+    /// SOL available for instant unstakes and LP withdrawals
+    pub sol_reserve: u64,
+    
+    /// SOL owed to the pool by its withdrawal tickets
+    pub pending_redemption_sol: u64,
+    
+    /// Fee at zero utilization (basis points)
+    pub min_fee_bps: u16,
+    
+    /// Fee at full utilization (basis points)
+    pub max_fee_bps: u16,
+    
+    /// Total fees earned by LPs historically
+    pub lifetime_fees: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl LiquidityPool {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // vault
+        32 + // lp_mint
+        8 +  // sol_reserve
+        8 +  // pending_redemption_sol
+        2 +  // min_fee_bps
+        2 +  // max_fee_bps
+        8 +  // lifetime_fees
+        1;   // bump
+
+    /// Total SOL value backing the LP tokens
+    pub fn total_value(&self) -> Result<u64> {
+        self.sol_reserve
+            .checked_add(self.pending_redemption_sol)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Calculate LP tokens to mint for a given SOL amount
+    pub fn calculate_lp_tokens(&self, sol_amount: u64, lp_supply: u64) -> Result<u64> {
+        let total_value = self.total_value()?;
+        if lp_supply == 0 || total_value == 0 {
+            // First deposit: 1:1 ratio
+            return Ok(sol_amount);
+        }
+
+        // lp = amount * lp_supply / total_value
+        (sol_amount as u128)
+            .checked_mul(lp_supply as u128)
+            .and_then(|v| v.checked_div(total_value as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Calculate SOL value for given LP tokens
+    pub fn lp_tokens_to_sol(&self, lp_amount: u64, lp_supply: u64) -> Result<u64> {
+        if lp_supply == 0 {
+            return Ok(0);
+        }
+
+        // sol = lp_amount * total_value / lp_supply
+        (lp_amount as u128)
+            .checked_mul(self.total_value()? as u128)
+            .and_then(|v| v.checked_div(lp_supply as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Fee for buying vault tokens worth `sol_value`, rising linearly from
+    /// `min_fee_bps` to `max_fee_bps` with the utilization after the swap
+    pub fn unstake_fee(&self, sol_value: u64) -> Result<u64> {
+        let total_value = self.total_value()? as u128;
+        require!(total_value > 0, ErrorCode::InsufficientPoolLiquidity);
+
+        // Share of the pool tied up in pending redemptions after this swap
+        let utilization_bps = (self.pending_redemption_sol as u128)
+            .checked_add(sol_value as u128)
+            .and_then(|v| v.checked_mul(BASIS_POINTS_DIVISOR as u128))
+            .map(|v| (v / total_value).min(BASIS_POINTS_DIVISOR as u128))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let fee_range = (self.max_fee_bps - self.min_fee_bps) as u128;
+        let fee_bps = self.min_fee_bps as u128
+            + fee_range * utilization_bps / BASIS_POINTS_DIVISOR as u128;
+
+        let fee = (sol_value as u128) * fee_bps / BASIS_POINTS_DIVISOR as u128;
+        u64::try_from(fee).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNIT: u64 = 1_000_000_000;
+
+    fn pool(sol_reserve: u64, pending_redemption_sol: u64) -> LiquidityPool {
+        LiquidityPool {
+            sol_reserve,
+            pending_redemption_sol,
+            min_fee_bps: 30,
+            max_fee_bps: 300,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fee_is_min_at_zero_utilization() {
+        let p = pool(1_000 * UNIT, 0);
+
+        // 0.01 SOL against 1000 SOL rounds down to 0 bps utilization
+        assert_eq!(p.unstake_fee(UNIT / 100).unwrap(), 30_000);
+    }
+
+    #[test]
+    fn fee_is_max_at_full_utilization() {
+        let p = pool(50 * UNIT, 50 * UNIT);
+
+        // Utilization after the swap would exceed 100% and is capped
+        assert_eq!(p.unstake_fee(60 * UNIT).unwrap(), 60 * UNIT * 300 / 10_000);
+        assert_eq!(p.unstake_fee(50 * UNIT).unwrap(), 50 * UNIT * 300 / 10_000);
+    }
+
+    #[test]
+    fn fee_rises_linearly_with_utilization() {
+        let p = pool(75 * UNIT, 25 * UNIT);
+
+        // 50% utilization after the swap: 30 + 270 / 2 = 165 bps
+        assert_eq!(p.unstake_fee(25 * UNIT).unwrap(), 25 * UNIT * 165 / 10_000);
+    }
+
+    #[test]
+    fn empty_pool_rejects_unstake() {
+        assert_eq!(
+            pool(0, 0).unstake_fee(UNIT).unwrap_err(),
+            ErrorCode::InsufficientPoolLiquidity.into()
+        );
+    }
+
+    #[test]
+    fn lp_tokens_track_pool_value() {
+        // First deposit mints 1:1
+        assert_eq!(pool(0, 0).calculate_lp_tokens(10 * UNIT, 0).unwrap(), 10 * UNIT);
+
+        // Pending redemptions count towards the pool value
+        let p = pool(60 * UNIT, 40 * UNIT);
+        assert_eq!(p.calculate_lp_tokens(10 * UNIT, 50 * UNIT).unwrap(), 5 * UNIT);
+        assert_eq!(p.lp_tokens_to_sol(5 * UNIT, 50 * UNIT).unwrap(), 10 * UNIT);
+        assert_eq!(p.lp_tokens_to_sol(UNIT, 0).unwrap(), 0);
+    }
+}
@@ -1,9 +1,23 @@
+pub mod balance_report;
+pub mod epoch_snapshot;
+pub mod exchange_rate_history;
 pub mod factory;
+pub mod liquidity_pool;
+pub mod pending_param_change;
 pub mod psol_controller;
+pub mod role_registry;
+pub mod validator_list;
 pub mod vault;
 pub mod withdrawal_ticket;
 
+pub use balance_report::*;
+pub use epoch_snapshot::*;
+pub use exchange_rate_history::*;
 pub use factory::*;
+pub use liquidity_pool::*;
+pub use pending_param_change::*;
 pub use psol_controller::*;
+pub use role_registry::*;
+pub use validator_list::*;
 pub use vault::*;
-pub use withdrawal_ticket::*;
\ No newline at end of file
+pub use withdrawal_ticket::*;
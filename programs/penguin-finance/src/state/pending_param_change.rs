@@ -0,0 +1,326 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{Factory, PsolController, Role, RoleRegistry};
+
+/// Protocol parameters that can only change through the timelock queue
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProtocolParam {
+    /// `Factory::protocol_fee_bps`
+    ProtocolFeeBps,
+    /// `PsolController::min_collateral_ratio`
+    MinCollateralRatio,
+    /// `PsolController::liquidation_threshold`
+    LiquidationThreshold,
+    /// `PsolController::liquidation_bonus`
+    LiquidationBonus,
+    /// Timelock delay, switching the unit to slots
+    TimelockDelaySlots,
+    /// Timelock delay, switching the unit to epochs
+    TimelockDelayEpochs,
+    /// `Factory::oracle_quorum`
+    OracleQuorum,
+    /// `Factory::oracle_tolerance_bps`
+    OracleToleranceBps,
+    /// `Factory::max_reward_apr_bps`
+    MaxRewardAprBps,
+    /// `Factory::max_rate_jump_bps`
+    MaxRateJumpBps,
+    /// `PsolController::twap_window_epochs`
+    TwapWindowEpochs,
+}
+
+impl ProtocolParam {
+    /// Role allowed to queue changes to this parameter, `None` if only the authority can
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            ProtocolParam::ProtocolFeeBps => Some(Role::FeeManager),
+            ProtocolParam::MinCollateralRatio
+            | ProtocolParam::LiquidationThreshold
+            | ProtocolParam::LiquidationBonus
+            | ProtocolParam::OracleToleranceBps
+            | ProtocolParam::MaxRewardAprBps
+            | ProtocolParam::MaxRateJumpBps
+            | ProtocolParam::TwapWindowEpochs => Some(Role::RiskManager),
+            ProtocolParam::TimelockDelaySlots
+            | ProtocolParam::TimelockDelayEpochs
+            | ProtocolParam::OracleQuorum => None,
+        }
+    }
+
+    /// Require that a signer may queue or cancel changes to this parameter
+    pub fn require_manager(
+        &self,
+        signer: &Pubkey,
+        factory: &Factory,
+        role_registry: &RoleRegistry,
+    ) -> Result<()> {
+        match self.required_role() {
+            Some(role) => role_registry.require_role(signer, role),
+            None => {
+                require_keys_eq!(*signer, factory.authority, ErrorCode::Unauthorized);
+                Ok(())
+            }
+        }
+    }
+
+    /// Current on-chain value of the parameter
+    pub fn current_value(&self, factory: &Factory, psol_controller: &PsolController) -> u64 {
+        match self {
+            ProtocolParam::ProtocolFeeBps => factory.protocol_fee_bps as u64,
+            ProtocolParam::MinCollateralRatio => psol_controller.min_collateral_ratio,
+            ProtocolParam::LiquidationThreshold => psol_controller.liquidation_threshold,
+            ProtocolParam::LiquidationBonus => psol_controller.liquidation_bonus,
+            ProtocolParam::TimelockDelaySlots | ProtocolParam::TimelockDelayEpochs => {
+                factory.timelock_delay
+            }
+            ProtocolParam::OracleQuorum => factory.oracle_quorum as u64,
+            ProtocolParam::OracleToleranceBps => factory.oracle_tolerance_bps as u64,
+            ProtocolParam::MaxRewardAprBps => factory.max_reward_apr_bps as u64,
+            ProtocolParam::MaxRateJumpBps => factory.max_rate_jump_bps as u64,
+            ProtocolParam::TwapWindowEpochs => psol_controller.twap_window_epochs,
+        }
+    }
+
+    /// Check a new value against the hard caps and the current risk parameters
+    pub fn validate(
+        &self,
+        value: u64,
+        psol_controller: &PsolController,
+    ) -> Result<()> {
+        match self {
+            ProtocolParam::ProtocolFeeBps => require!(
+                value <= MAX_PROTOCOL_FEE_BPS as u64,
+                ErrorCode::ProtocolFeeTooHigh
+            ),
+            // Minting must always require more collateral than liquidation does
+            ProtocolParam::MinCollateralRatio => require!(
+                value > psol_controller.liquidation_threshold,
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::LiquidationThreshold => require!(
+                value >= BASIS_POINTS_DIVISOR && value < psol_controller.min_collateral_ratio,
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::LiquidationBonus => require!(
+                value <= MAX_LIQUIDATION_BONUS,
+                ErrorCode::InvalidParamValue
+            ),
+            // A zero delay disables the timelock, a huge one overflows every later queue
+            ProtocolParam::TimelockDelaySlots => require!(
+                (MIN_TIMELOCK_DELAY_SLOTS..=MAX_TIMELOCK_DELAY_SLOTS).contains(&value),
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::TimelockDelayEpochs => require!(
+                (MIN_TIMELOCK_DELAY_EPOCHS..=MAX_TIMELOCK_DELAY_EPOCHS).contains(&value),
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::OracleQuorum => require!(
+                value >= 1 && value <= MAX_ORACLE_REPORTS as u64,
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::OracleToleranceBps => require!(
+                value <= BASIS_POINTS_DIVISOR,
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::MaxRewardAprBps => require!(
+                value > 0 && value <= u16::MAX as u64,
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::MaxRateJumpBps => require!(
+                value > 0 && value <= BASIS_POINTS_DIVISOR,
+                ErrorCode::InvalidParamValue
+            ),
+            ProtocolParam::TwapWindowEpochs => require!(
+                value <= RATE_HISTORY_LEN as u64,
+                ErrorCode::InvalidParamValue
+            ),
+        }
+        Ok(())
+    }
+
+    /// Write the new value into the factory or pSOL controller
+    pub fn apply(
+        &self,
+        value: u64,
+        factory: &mut Factory,
+        psol_controller: &mut PsolController,
+    ) {
+        match self {
+            // Bounded by `MAX_PROTOCOL_FEE_BPS` in `validate`
+            ProtocolParam::ProtocolFeeBps => factory.protocol_fee_bps = value as u16,
+            ProtocolParam::MinCollateralRatio => psol_controller.min_collateral_ratio = value,
+            ProtocolParam::LiquidationThreshold => psol_controller.liquidation_threshold = value,
+            ProtocolParam::LiquidationBonus => psol_controller.liquidation_bonus = value,
+            ProtocolParam::TimelockDelaySlots => {
+                factory.timelock_unit = TimelockUnit::Slots;
+                factory.timelock_delay = value;
+            }
+            ProtocolParam::TimelockDelayEpochs => {
+                factory.timelock_unit = TimelockUnit::Epochs;
+                factory.timelock_delay = value;
+            }
+            // Bounded by `MAX_ORACLE_REPORTS` and `BASIS_POINTS_DIVISOR` in `validate`
+            ProtocolParam::OracleQuorum => factory.oracle_quorum = value as u8,
+            ProtocolParam::OracleToleranceBps => factory.oracle_tolerance_bps = value as u16,
+            // Bounded by `u16::MAX` and `BASIS_POINTS_DIVISOR` in `validate`
+            ProtocolParam::MaxRewardAprBps => factory.max_reward_apr_bps = value as u16,
+            ProtocolParam::MaxRateJumpBps => factory.max_rate_jump_bps = value as u16,
+            ProtocolParam::TwapWindowEpochs => psol_controller.twap_window_epochs = value,
+        }
+    }
+}
+
+/// Unit the timelock delay is measured in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimelockUnit {
+    Slots,
+    Epochs,
+}
+
+impl TimelockUnit {
+    /// Current point in time expressed in this unit
+    pub fn now(&self, clock: &Clock) -> u64 {
+        match self {
+            TimelockUnit::Slots => clock.slot,
+            TimelockUnit::Epochs => clock.epoch,
+        }
+    }
+}
+
+#[account]
+pub struct PendingParamChange {
+    /// Reference to factory
+    pub factory: Pubkey,
+    
+    /// Sequential change ID
+    pub change_id: u64,
+    
+    /// Parameter being changed
+    pub param: ProtocolParam,
+    
+    /// Value applied on execution
+    pub new_value: u64,
+    
+    /// Unit of `queued_at` and `executable_at`
+    pub unit: TimelockUnit,
+    
+    /// Slot or epoch when the change was queued
+    pub queued_at: u64,
+    
+    /// Slot or epoch from which the change can be executed
+    pub executable_at: u64,
+    
+    /// Account that queued the change and receives the rent back
+    pub proposer: Pubkey,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingParamChange {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // factory
+        8 +  // change_id
+        1 +  // param
+        8 +  // new_value
+        1 +  // unit
+        8 +  // queued_at
+        8 +  // executable_at
+        32 + // proposer
+        1;   // bump
+
+    /// Check if the timelock has elapsed
+    pub fn is_executable(&self, clock: &Clock) -> bool {
+        self.unit.now(clock) >= self.executable_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn controller(min_collateral_ratio: u64, liquidation_threshold: u64) -> PsolController {
+        PsolController {
+            min_collateral_ratio,
+            liquidation_threshold,
+            liquidation_bonus: 500,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn protocol_fee_is_capped() {
+        let psol_controller = controller(15_000, 12_000);
+        let param = ProtocolParam::ProtocolFeeBps;
+
+        assert!(param.validate(MAX_PROTOCOL_FEE_BPS as u64, &psol_controller).is_ok());
+        assert_eq!(
+            param.validate(MAX_PROTOCOL_FEE_BPS as u64 + 1, &psol_controller),
+            Err(ErrorCode::ProtocolFeeTooHigh.into())
+        );
+    }
+
+    #[test]
+    fn collateral_ratio_stays_above_liquidation_threshold() {
+        let psol_controller = controller(15_000, 12_000);
+
+        assert!(ProtocolParam::MinCollateralRatio.validate(12_001, &psol_controller).is_ok());
+        assert_eq!(
+            ProtocolParam::MinCollateralRatio.validate(12_000, &psol_controller),
+            Err(ErrorCode::InvalidParamValue.into())
+        );
+
+        let param = ProtocolParam::LiquidationThreshold;
+        assert!(param.validate(BASIS_POINTS_DIVISOR, &psol_controller).is_ok());
+        assert!(param.validate(BASIS_POINTS_DIVISOR - 1, &psol_controller).is_err());
+        assert!(param.validate(15_000, &psol_controller).is_err());
+    }
+
+    #[test]
+    fn liquidation_bonus_is_capped() {
+        let psol_controller = controller(15_000, 12_000);
+        let param = ProtocolParam::LiquidationBonus;
+
+        assert!(param.validate(MAX_LIQUIDATION_BONUS, &psol_controller).is_ok());
+        assert!(param.validate(MAX_LIQUIDATION_BONUS + 1, &psol_controller).is_err());
+    }
+
+    #[test]
+    fn timelock_delay_is_bounded_in_its_unit() {
+        let psol_controller = controller(15_000, 12_000);
+
+        for (param, min, max) in [
+            (ProtocolParam::TimelockDelaySlots, MIN_TIMELOCK_DELAY_SLOTS, MAX_TIMELOCK_DELAY_SLOTS),
+            (ProtocolParam::TimelockDelayEpochs, MIN_TIMELOCK_DELAY_EPOCHS, MAX_TIMELOCK_DELAY_EPOCHS),
+        ] {
+            assert!(param.validate(min, &psol_controller).is_ok());
+            assert!(param.validate(max, &psol_controller).is_ok());
+            assert_eq!(
+                param.validate(0, &psol_controller),
+                Err(ErrorCode::InvalidParamValue.into())
+            );
+            assert!(param.validate(max + 1, &psol_controller).is_err());
+        }
+    }
+
+    #[test]
+    fn executable_once_delay_elapses_in_its_unit() {
+        let change = PendingParamChange {
+            factory: Pubkey::default(),
+            change_id: 0,
+            param: ProtocolParam::ProtocolFeeBps,
+            new_value: 100,
+            unit: TimelockUnit::Epochs,
+            queued_at: 10,
+            executable_at: 12,
+            proposer: Pubkey::default(),
+            bump: 0,
+        };
+        let clock = |slot, epoch| Clock { slot, epoch, ..Clock::default() };
+
+        assert!(!change.is_executable(&clock(1_000_000, 11)));
+        assert!(change.is_executable(&clock(0, 12)));
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default)]
 pub struct PsolController {
     /// Reference to factory
     pub factory: Pubkey,
@@ -26,6 +27,9 @@ pub struct PsolController {
     /// Number of active positions
     pub active_positions: u64,
     
+    /// Epochs of vault rate history averaged when valuing collateral, 0 for spot only
+    pub twap_window_epochs: u64,
+    
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -40,6 +44,7 @@ impl PsolController {
         8 +  // liquidation_threshold
         8 +  // liquidation_bonus
         8 +  // active_positions
+        8 +  // twap_window_epochs
         1;   // bump
 
     /// Calculate global collateralization ratio
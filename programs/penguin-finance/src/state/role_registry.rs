@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+
+/// Operational roles granted by the factory authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// Can pause the protocol, but not unpause it
+    Pauser,
+    /// Can queue protocol fee changes
+    FeeManager,
+    /// Can queue pSOL controller risk parameter changes
+    RiskManager,
+    /// Can report vault balances
+    OracleReporter,
+    /// Can rotate the protocol treasury
+    TreasuryManager,
+}
+
+impl Role {
+    /// Bit representing this role in `RoleMember::roles`
+    pub fn mask(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RoleMember {
+    /// Key holding the roles
+    pub member: Pubkey,
+    
+    /// Bitmask of granted roles
+    pub roles: u8,
+}
+
+impl RoleMember {
+    pub const LEN: usize = 32 + // member
+        1;   // roles
+}
+
+#[account]
+pub struct RoleRegistry {
+    /// Reference to factory
+    pub factory: Pubkey,
+    
+    /// Keys holding at least one role
+    pub members: Vec<RoleMember>,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RoleRegistry {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // factory
+        4 + MAX_ROLE_MEMBERS * RoleMember::LEN + // members
+        1;   // bump
+
+    /// Check if a key holds the given role
+    pub fn has_role(&self, member: &Pubkey, role: Role) -> bool {
+        self.members
+            .iter()
+            .any(|m| m.member == *member && m.roles & role.mask() != 0)
+    }
+
+    /// Require that a key holds the given role
+    pub fn require_role(&self, member: &Pubkey, role: Role) -> Result<()> {
+        require!(self.has_role(member, role), ErrorCode::MissingRole);
+        Ok(())
+    }
+
+    /// Grant a role, adding the member if needed
+    pub fn grant(&mut self, member: Pubkey, role: Role) -> Result<()> {
+        if let Some(existing) = self.members.iter_mut().find(|m| m.member == member) {
+            existing.roles |= role.mask();
+            return Ok(());
+        }
+
+        require!(
+            self.members.len() < MAX_ROLE_MEMBERS,
+            ErrorCode::RoleRegistryFull
+        );
+        self.members.push(RoleMember {
+            member,
+            roles: role.mask(),
+        });
+        Ok(())
+    }
+
+    /// Revoke a role, dropping the member once it holds no roles
+    pub fn revoke(&mut self, member: &Pubkey, role: Role) -> Result<()> {
+        let index = self
+            .members
+            .iter()
+            .position(|m| m.member == *member && m.roles & role.mask() != 0)
+            .ok_or(ErrorCode::MissingRole)?;
+
+        self.members[index].roles &= !role.mask();
+        if self.members[index].roles == 0 {
+            self.members.swap_remove(index);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> RoleRegistry {
+        RoleRegistry {
+            factory: Pubkey::default(),
+            members: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn grants_accumulate_in_one_member() {
+        let mut registry = registry();
+        let key = Pubkey::new_unique();
+
+        registry.grant(key, Role::Pauser).unwrap();
+        registry.grant(key, Role::OracleReporter).unwrap();
+        registry.grant(key, Role::OracleReporter).unwrap();
+
+        assert_eq!(registry.members.len(), 1);
+        assert_eq!(
+            registry.members[0].roles,
+            Role::Pauser.mask() | Role::OracleReporter.mask()
+        );
+        assert!(registry.has_role(&key, Role::Pauser));
+        assert!(!registry.has_role(&key, Role::FeeManager));
+        assert!(!registry.has_role(&Pubkey::new_unique(), Role::Pauser));
+    }
+
+    #[test]
+    fn revoke_clears_one_bit_and_drops_empty_members() {
+        let mut registry = registry();
+        let key = Pubkey::new_unique();
+        registry.grant(key, Role::FeeManager).unwrap();
+        registry.grant(key, Role::RiskManager).unwrap();
+
+        registry.revoke(&key, Role::FeeManager).unwrap();
+        assert!(!registry.has_role(&key, Role::FeeManager));
+        assert!(registry.has_role(&key, Role::RiskManager));
+
+        registry.revoke(&key, Role::RiskManager).unwrap();
+        assert!(registry.members.is_empty());
+        assert_eq!(
+            registry.revoke(&key, Role::RiskManager),
+            Err(ErrorCode::MissingRole.into())
+        );
+    }
+
+    #[test]
+    fn grant_rejects_new_members_when_full() {
+        let mut registry = registry();
+        for _ in 0..MAX_ROLE_MEMBERS {
+            registry.grant(Pubkey::new_unique(), Role::Pauser).unwrap();
+        }
+        let first = registry.members[0].member;
+
+        assert_eq!(
+            registry.grant(Pubkey::new_unique(), Role::Pauser),
+            Err(ErrorCode::RoleRegistryFull.into())
+        );
+        assert!(registry.grant(first, Role::TreasuryManager).is_ok());
+    }
+}
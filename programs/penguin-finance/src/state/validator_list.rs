@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+
+/// Lifecycle of a validator within a vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ValidatorStatus {
+    /// Delegated and earning
+    Active = 0,
+    /// Stake is cooling down
+    Deactivating = 1,
+    /// Stake fully withdrawn
+    Removed = 2,
+}
+
+#[zero_copy]
+pub struct ValidatorEntry {
+    /// Validator vote account
+    pub vote_account: Pubkey,
+    
+    /// Vault stake account PDA delegated to this validator
+    pub stake_account: Pubkey,
+    
+    /// Lamports in the main stake account
+    pub active_lamports: u64,
+    
+    /// Lamports in the transient stake account
+    pub transient_lamports: u64,
+    
+    /// Last epoch this entry was updated
+    pub last_update_epoch: u64,
+    
+    /// `ValidatorStatus` as u8
+    pub status: u8,
+    
+    pub _padding: [u8; 7],
+}
+
+impl ValidatorEntry {
+    pub const LEN: usize = 32 + // vote_account
+        32 + // stake_account
+        8 +  // active_lamports
+        8 +  // transient_lamports
+        8 +  // last_update_epoch
+        1 +  // status
+        7;   // padding
+
+    pub fn status(&self) -> ValidatorStatus {
+        match self.status {
+            0 => ValidatorStatus::Active,
+            1 => ValidatorStatus::Deactivating,
+            _ => ValidatorStatus::Removed,
+        }
+    }
+
+    pub fn set_status(&mut self, status: ValidatorStatus) {
+        self.status = status as u8;
+    }
+
+    /// Total lamports held for this validator
+    pub fn total_lamports(&self) -> Result<u64> {
+        self.active_lamports
+            .checked_add(self.transient_lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+}
+
+#[account(zero_copy)]
+pub struct ValidatorList {
+    /// Vault owning this list
+    pub vault: Pubkey,
+    
+    /// Number of entries in use
+    pub count: u32,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+    
+    pub _padding: [u8; 3],
+    
+    /// Validator entries, only the first `count` are in use
+    pub validators: [ValidatorEntry; MAX_VALIDATORS],
+}
+
+impl ValidatorList {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // vault
+        4 +  // count
+        1 +  // bump
+        3 +  // padding
+        MAX_VALIDATORS * ValidatorEntry::LEN; // validators
+
+    /// Entries in use
+    pub fn entries(&self) -> &[ValidatorEntry] {
+        &self.validators[..self.count as usize]
+    }
+
+    /// Find the entry for a vote account
+    pub fn find(&self, vote_account: &Pubkey) -> Option<&ValidatorEntry> {
+        self.entries()
+            .iter()
+            .find(|entry| entry.vote_account == *vote_account)
+    }
+
+    /// Find the entry for a vote account, mutably
+    pub fn find_mut(&mut self, vote_account: &Pubkey) -> Option<&mut ValidatorEntry> {
+        let count = self.count as usize;
+        self.validators[..count]
+            .iter_mut()
+            .find(|entry| entry.vote_account == *vote_account)
+    }
+
+    /// Return the entry for a vote account, adding it if it is not listed yet.
+    /// A previously removed entry is reused as-is.
+    pub fn find_or_add(
+        &mut self,
+        vote_account: Pubkey,
+        stake_account: Pubkey,
+    ) -> Result<&mut ValidatorEntry> {
+        let index = match self
+            .entries()
+            .iter()
+            .position(|entry| entry.vote_account == vote_account)
+        {
+            Some(index) => index,
+            None => {
+                let index = self.count as usize;
+                require!(index < MAX_VALIDATORS, ErrorCode::ValidatorListFull);
+                self.validators[index] = ValidatorEntry {
+                    vote_account,
+                    stake_account,
+                    active_lamports: 0,
+                    transient_lamports: 0,
+                    last_update_epoch: 0,
+                    status: ValidatorStatus::Removed as u8,
+                    _padding: [0; 7],
+                };
+                self.count += 1;
+                index
+            }
+        };
+        Ok(&mut self.validators[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list() -> Box<ValidatorList> {
+        Box::new(bytemuck::Zeroable::zeroed())
+    }
+
+    #[test]
+    fn new_validators_start_removed_and_empty() {
+        let mut list = list();
+        let vote_account = Pubkey::new_unique();
+        let stake_account = Pubkey::new_unique();
+
+        let entry = list.find_or_add(vote_account, stake_account).unwrap();
+        assert_eq!(entry.status(), ValidatorStatus::Removed);
+        assert_eq!(entry.stake_account, stake_account);
+        assert_eq!(entry.total_lamports().unwrap(), 0);
+
+        assert_eq!(list.count, 1);
+        assert!(list.find(&vote_account).is_some());
+        assert!(list.find(&Pubkey::new_unique()).is_none());
+    }
+
+    #[test]
+    fn removed_validator_reuses_its_slot() {
+        let mut list = list();
+        let vote_account = Pubkey::new_unique();
+        let stake_account = Pubkey::new_unique();
+        list.find_or_add(Pubkey::new_unique(), Pubkey::new_unique()).unwrap();
+
+        let entry = list.find_or_add(vote_account, stake_account).unwrap();
+        entry.set_status(ValidatorStatus::Active);
+        entry.active_lamports = 5_000;
+        list.find_mut(&vote_account).unwrap().set_status(ValidatorStatus::Removed);
+
+        let entry = list.find_or_add(vote_account, Pubkey::new_unique()).unwrap();
+        assert_eq!(entry.stake_account, stake_account);
+        assert_eq!(entry.active_lamports, 5_000);
+        assert_eq!(list.count, 2);
+    }
+
+    #[test]
+    fn full_list_rejects_new_validators() {
+        let mut list = list();
+        for _ in 0..MAX_VALIDATORS {
+            list.find_or_add(Pubkey::new_unique(), Pubkey::new_unique()).unwrap();
+        }
+        let listed = list.validators[0].vote_account;
+
+        assert!(list.find_or_add(listed, Pubkey::new_unique()).is_ok());
+        assert_eq!(
+            list.find_or_add(Pubkey::new_unique(), Pubkey::new_unique()).err(),
+            Some(ErrorCode::ValidatorListFull.into())
+        );
+    }
+}
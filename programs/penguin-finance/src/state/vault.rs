@@ -1,6 +1,36 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+use crate::errors::ErrorCode;
+
+/// Vault lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum VaultStatus {
+    /// Accepting deposits and staking
+    #[default]
+    Active,
+    /// Existing stake keeps earning, no new deposits
+    DepositsClosed,
+    /// Stake is being unwound so holders can exit pro rata
+    WindingDown,
+    /// All shares redeemed and the vault closed by `close_vault`
+    Closed,
+}
+
+/// Outcome of a balance update, used to mint fee shares and emit events
+#[derive(Clone, Copy, Default, Debug)]
+pub struct RewardDistribution {
+    pub rewards: u64,
+    pub loss: u64,
+    pub protocol_fee: u64,
+    pub operator_fee: u64,
+    pub staker_rewards: u64,
+    pub protocol_shares: u64,
+    pub operator_shares: u64,
+}
+
 #[account]
+#[derive(Default)]
 pub struct Vault {
     /// Reference to factory
     pub factory: Pubkey,
@@ -11,21 +41,36 @@ pub struct Vault {
     /// Vault operator/authority
     pub operator: Pubkey,
     
+    /// Proposed new operator awaiting acceptance (default if none)
+    pub pending_operator: Pubkey,
+    
     /// Vault token mint (pVault-X)
     pub vault_token_mint: Pubkey,
     
     /// Operator fee in basis points (e.g., 500 = 5%)
     pub fee_basis_points: u16,
     
+    /// Announced operator fee increase
+    pub pending_fee_basis_points: u16,
+    
+    /// Epoch from which the announced fee increase applies
+    pub pending_fee_epoch: u64,
+    
     /// Maximum SOL capacity for this vault
     pub max_capacity: u64,
     
     /// Total SOL staked to validators
     pub total_staked: u64,
     
-    /// Buffered SOL not yet staked
+    /// Buffered SOL not yet staked, held by the reserve PDA on top of its rent
     pub buffered_sol: u64,
     
+    /// SOL owed to withdrawal tickets still waiting in the queue
+    pub pending_withdrawal_sol: u64,
+    
+    /// Buffered SOL set aside for fulfilled, unclaimed tickets
+    pub reserved_sol: u64,
+    
     /// Total vault token shares minted
     pub total_shares: u64,
     
@@ -35,8 +80,14 @@ pub struct Vault {
     /// Last epoch rewards were claimed
     pub last_reward_epoch: u64,
     
-    /// Whether vault is accepting deposits
-    pub accepting_deposits: bool,
+    /// Highest exchange rate fees were charged up to (scaled by 1e9)
+    pub high_water_mark: u64,
+    
+    /// Lifecycle status
+    pub status: VaultStatus,
+    
+    /// Vault-level pause overrides (`PAUSE_*` bits)
+    pub pause_flags: u8,
     
     /// Vault name
     pub vault_name: String,
@@ -47,6 +98,27 @@ pub struct Vault {
     /// Total rewards earned historically
     pub lifetime_rewards: u64,
     
+    /// Balance update held back by the circuit breaker, awaiting review
+    pub quarantined: bool,
+    
+    /// Total staked reported by the quarantined update
+    pub quarantined_total_staked: u64,
+    
+    /// Epoch of the quarantined update
+    pub quarantined_epoch: u64,
+    
+    /// Next balance update skips the bounds once, set when a review approves
+    pub breaker_override: bool,
+    
+    /// ID of the next withdrawal ticket
+    pub next_ticket_id: u64,
+    
+    /// ID of the oldest ticket not yet fulfilled (head of the withdrawal queue)
+    pub next_fulfill_ticket_id: u64,
+    
+    /// Bump seed for the SOL reserve PDA
+    pub reserve_bump: u8,
+    
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -56,18 +128,32 @@ impl Vault {
         32 +  // factory
         8 +   // vault_id
         32 +  // operator
+        32 +  // pending_operator
         32 +  // vault_token_mint
         2 +   // fee_basis_points
+        2 +   // pending_fee_basis_points
+        8 +   // pending_fee_epoch
         8 +   // max_capacity
         8 +   // total_staked
         8 +   // buffered_sol
+        8 +   // pending_withdrawal_sol
+        8 +   // reserved_sol
         8 +   // total_shares
         8 +   // total_assets
         8 +   // last_reward_epoch
-        1 +   // accepting_deposits
+        8 +   // high_water_mark
+        1 +   // status
+        1 +   // pause_flags
         4 + 32 + // vault_name (String with max 32 chars)
         2 +   // active_validators
         8 +   // lifetime_rewards
+        1 +   // quarantined
+        8 +   // quarantined_total_staked
+        8 +   // quarantined_epoch
+        1 +   // breaker_override
+        8 +   // next_ticket_id
+        8 +   // next_fulfill_ticket_id
+        1 +   // reserve_bump
         1;    // bump
 
     /// Calculate current exchange rate (SOL per vault token)
@@ -80,7 +166,7 @@ impl Vault {
         self.total_assets
             .checked_mul(1_000_000_000)
             .and_then(|v| v.checked_div(self.total_shares))
-            .ok_or(error!(crate::errors::ErrorCode::ArithmeticOverflow))
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
     }
 
     /// Calculate shares to mint for a given SOL amount
@@ -94,7 +180,7 @@ impl Vault {
         sol_amount
             .checked_mul(self.total_shares)
             .and_then(|v| v.checked_div(self.total_assets))
-            .ok_or(error!(crate::errors::ErrorCode::ArithmeticOverflow))
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
     }
 
     /// Calculate SOL value for given shares
@@ -107,13 +193,609 @@ impl Vault {
         shares
             .checked_mul(self.total_assets)
             .and_then(|v| v.checked_div(self.total_shares))
-            .ok_or(error!(crate::errors::ErrorCode::ArithmeticOverflow))
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Move withdrawn stake into the buffer, up to the stake the vault has
+    /// booked. Per-validator tracking lags the oracle path, so only the
+    /// aggregate is trusted: any difference on the remaining stake is picked
+    /// up by the next balance update, and lamports beyond all booked stake
+    /// stay in the reserve for `sync_vault_reserve`. Returns the amount booked
+    pub fn book_stake_withdrawal(&mut self, withdrawn: u64) -> Result<u64> {
+        let booked = withdrawn.min(self.total_staked);
+
+        self.total_staked = self
+            .total_staked
+            .checked_sub(booked)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        self.buffered_sol = self
+            .buffered_sol
+            .checked_add(booked)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        Ok(booked)
+    }
+
+    /// Buffered SOL not owed to withdrawal tickets, the only part that may be staked
+    pub fn free_buffer(&self) -> u64 {
+        self.buffered_sol
+            .saturating_sub(self.reserved_sol)
+            .saturating_sub(self.pending_withdrawal_sol)
+    }
+
+    /// Take burned shares out of the vault and queue their SOL value behind
+    /// earlier tickets. Returns the new ticket ID and its SOL amount
+    pub fn queue_withdrawal(&mut self, vault_token_amount: u64) -> Result<(u64, u64)> {
+        let expected_sol = self.shares_to_sol(vault_token_amount)?;
+
+        self.total_shares = self
+            .total_shares
+            .checked_sub(vault_token_amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        self.total_assets = self
+            .total_assets
+            .checked_sub(expected_sol)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+        // Queued until `fulfill_withdrawals` reserves buffer for it in request order
+        self.pending_withdrawal_sol = self
+            .pending_withdrawal_sol
+            .checked_add(expected_sol)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let ticket_id = self.next_ticket_id;
+        self.next_ticket_id = ticket_id
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        Ok((ticket_id, expected_sol))
+    }
+
+    /// Set aside buffered SOL for the ticket at the head of the queue.
+    /// Returns false if the unreserved buffer cannot cover it yet
+    pub fn reserve_for_ticket(&mut self, amount: u64) -> Result<bool> {
+        let unreserved = self.buffered_sol.saturating_sub(self.reserved_sol);
+        if unreserved < amount {
+            return Ok(false);
+        }
+
+        self.reserved_sol = self
+            .reserved_sol
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.pending_withdrawal_sol = self
+            .pending_withdrawal_sol
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        self.next_fulfill_ticket_id = self
+            .next_fulfill_ticket_id
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(true)
+    }
+
+    /// Release the reserve of a claimed ticket from the buffer
+    pub fn release_reserved(&mut self, amount: u64) -> Result<()> {
+        self.buffered_sol = self
+            .buffered_sol
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        self.reserved_sol = self
+            .reserved_sol
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        Ok(())
+    }
+
+    /// Check if vault is accepting deposits
+    pub fn is_accepting_deposits(&self) -> bool {
+        self.status == VaultStatus::Active
+    }
+
+    /// Check if vault is unwinding or closed
+    pub fn is_winding_down(&self) -> bool {
+        matches!(self.status, VaultStatus::WindingDown | VaultStatus::Closed)
     }
 
     /// Check if vault has capacity for additional deposits
     pub fn has_capacity(&self, amount: u64) -> bool {
         self.total_assets
             .checked_add(amount)
-            .map_or(false, |new_total| new_total <= self.max_capacity)
+            .is_some_and(|new_total| new_total <= self.max_capacity)
+    }
+
+    /// Check if a fee increase has been announced
+    pub fn has_pending_fee(&self) -> bool {
+        self.pending_fee_basis_points > self.fee_basis_points
+    }
+
+    /// Apply an announced fee increase once its notice period is over
+    pub fn apply_pending_fee(&mut self, epoch: u64) {
+        if self.has_pending_fee() && epoch >= self.pending_fee_epoch {
+            self.fee_basis_points = self.pending_fee_basis_points;
+            self.pending_fee_basis_points = 0;
+            self.pending_fee_epoch = 0;
+        }
+    }
+
+    /// Check a new total staked balance against the implied APR and
+    /// exchange-rate jump caps. Passes once after a review approved it
+    pub fn within_reward_bounds(
+        &self,
+        new_total_staked: u64,
+        epoch: u64,
+        max_reward_apr_bps: u16,
+        max_rate_jump_bps: u16,
+    ) -> Result<bool> {
+        // Total assets move by the same amount as the staked balance
+        self.change_within_bounds(
+            self.total_staked,
+            new_total_staked.saturating_sub(self.total_staked),
+            new_total_staked.abs_diff(self.total_staked),
+            epoch,
+            max_reward_apr_bps,
+            max_rate_jump_bps,
+        )
+    }
+
+    /// Check a reserve surplus against the same caps, as a return on total assets
+    pub fn surplus_within_bounds(
+        &self,
+        surplus: u64,
+        epoch: u64,
+        max_reward_apr_bps: u16,
+        max_rate_jump_bps: u16,
+    ) -> Result<bool> {
+        self.change_within_bounds(
+            self.total_assets,
+            surplus,
+            surplus,
+            epoch,
+            max_reward_apr_bps,
+            max_rate_jump_bps,
+        )
+    }
+
+    /// `rewards` earned on `base` since the last reward epoch must stay under
+    /// the APR cap, and `assets_delta` under the exchange-rate jump cap
+    fn change_within_bounds(
+        &self,
+        base: u64,
+        rewards: u64,
+        assets_delta: u64,
+        epoch: u64,
+        max_reward_apr_bps: u16,
+        max_rate_jump_bps: u16,
+    ) -> Result<bool> {
+        if self.breaker_override || self.total_shares == 0 {
+            return Ok(true);
+        }
+
+        let epochs_covered = epoch.saturating_sub(self.last_reward_epoch).max(1) as u128;
+
+        // rewards * epochs per year / (base * epochs covered) <= max APR
+        let implied_apr = (rewards as u128)
+            .checked_mul(EPOCHS_PER_YEAR as u128 * BASIS_POINTS_DIVISOR as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let max_apr = (base as u128)
+            .checked_mul(epochs_covered * max_reward_apr_bps as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if implied_apr > max_apr {
+            return Ok(false);
+        }
+
+        let assets_delta = assets_delta as u128;
+        let max_delta = (self.total_assets as u128)
+            .checked_mul(max_rate_jump_bps as u128)
+            .map(|v| v / BASIS_POINTS_DIVISOR as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(assets_delta <= max_delta)
+    }
+
+    /// Hold back a balance update for review and stop new pSOL being
+    /// minted against this vault
+    pub fn quarantine(&mut self, new_total_staked: u64, epoch: u64) {
+        self.quarantined = true;
+        self.quarantined_total_staked = new_total_staked;
+        self.quarantined_epoch = epoch;
+        self.pause_flags |= PAUSE_MINTING;
+    }
+
+    /// Assets needed to bring the exchange rate back up to the high-water mark
+    pub fn high_water_mark_shortfall(&self) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(0);
+        }
+
+        // Round up so a partial recovery is never treated as new gains
+        let target = (self.high_water_mark as u128)
+            .checked_mul(self.total_shares as u128)
+            .and_then(|v| v.checked_add(999_999_999))
+            .map(|v| v / 1_000_000_000)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        let shortfall = target.saturating_sub(self.total_assets as u128);
+        u64::try_from(shortfall).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Book a new total staked balance, at most once per epoch. Gains above
+    /// the high-water mark are split into protocol and operator fees, paid
+    /// out as newly minted vault shares; losses lower total assets and carry
+    /// no fees. Epochs missed since the last update are booked together, so
+    /// fees are charged once on the combined change
+    pub fn distribute_rewards(
+        &mut self,
+        new_total_staked: u64,
+        protocol_fee_bps: u16,
+        epoch: u64,
+    ) -> Result<RewardDistribution> {
+        require!(
+            epoch > self.last_reward_epoch,
+            ErrorCode::RewardsAlreadyClaimed
+        );
+
+        // A review approval covers a single update
+        self.breaker_override = false;
+
+        // Announced fee increases only take effect once their notice period is over
+        self.apply_pending_fee(epoch);
+
+        // Calculate rewards earned
+        let old_total_staked = self.total_staked;
+
+        // Slashing or lost lamports: write the loss down against all holders
+        if new_total_staked < old_total_staked {
+            let loss = old_total_staked - new_total_staked;
+
+            self.total_staked = new_total_staked;
+            self.total_assets = self
+                .total_assets
+                .checked_sub(loss)
+                .ok_or(ErrorCode::ArithmeticUnderflow)?;
+            self.last_reward_epoch = epoch;
+
+            return Ok(RewardDistribution {
+                loss,
+                ..Default::default()
+            });
+        }
+
+        let rewards = new_total_staked
+            .checked_sub(old_total_staked)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+        self.total_staked = new_total_staked;
+        self.last_reward_epoch = epoch;
+
+        if rewards == 0 {
+            return Ok(RewardDistribution::default());
+        }
+
+        self.split_rewards(rewards, protocol_fee_bps)
+    }
+
+    /// Book SOL found in the reserve above `buffered_sol` (direct transfers,
+    /// tips, stake withdrawal leftovers) as rewards, with the same fee split
+    /// as a balance update
+    pub fn book_reserve_surplus(
+        &mut self,
+        surplus: u64,
+        protocol_fee_bps: u16,
+        epoch: u64,
+    ) -> Result<RewardDistribution> {
+        // A review approval covers a single update
+        self.breaker_override = false;
+
+        self.apply_pending_fee(epoch);
+
+        self.buffered_sol = self
+            .buffered_sol
+            .checked_add(surplus)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        self.split_rewards(surplus, protocol_fee_bps)
+    }
+
+    /// Add rewards to total assets, charging protocol and operator fees on
+    /// the part above the high-water mark as newly minted vault shares
+    fn split_rewards(&mut self, rewards: u64, protocol_fee_bps: u16) -> Result<RewardDistribution> {
+        // Rewards that only recover earlier losses are not charged again, and
+        // without shares outstanding there is nobody to mint fee shares against
+        let fee_eligible = if self.total_shares > 0 {
+            rewards.saturating_sub(self.high_water_mark_shortfall()?)
+        } else {
+            0
+        };
+
+        // Calculate fees
+        let protocol_fee = fee_eligible
+            .checked_mul(protocol_fee_bps as u64)
+            .and_then(|v| v.checked_div(BASIS_POINTS_DIVISOR))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let remaining_after_protocol = fee_eligible
+            .checked_sub(protocol_fee)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+        let operator_fee = remaining_after_protocol
+            .checked_mul(self.fee_basis_points as u64)
+            .and_then(|v| v.checked_div(BASIS_POINTS_DIVISOR))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        // Calculate fee shares to mint
+        let total_fee_amount = protocol_fee
+            .checked_add(operator_fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let staker_rewards = rewards
+            .checked_sub(total_fee_amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+        let fee_shares = if self.total_shares > 0 {
+            total_fee_amount
+                .checked_mul(self.total_shares)
+                .and_then(|v| v.checked_div(self.total_assets))
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+        } else {
+            0
+        };
+
+        // Split fee shares between protocol and operator
+        let protocol_shares = if fee_shares > 0 {
+            fee_shares
+                .checked_mul(protocol_fee)
+                .and_then(|v| v.checked_div(total_fee_amount))
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+        } else {
+            0
+        };
+
+        let operator_shares = fee_shares
+            .checked_sub(protocol_shares)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+        // Update vault state
+        self.total_assets = self
+            .total_assets
+            .checked_add(rewards)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.total_shares = self
+            .total_shares
+            .checked_add(fee_shares)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.lifetime_rewards = self
+            .lifetime_rewards
+            .checked_add(rewards)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.high_water_mark = self.high_water_mark.max(self.exchange_rate()?);
+
+        Ok(RewardDistribution {
+            rewards,
+            loss: 0,
+            protocol_fee,
+            operator_fee,
+            staker_rewards,
+            protocol_shares,
+            operator_shares,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Amounts are kept small so `exchange_rate`'s u64 scaling can't overflow
+    const UNIT: u64 = 1_000_000;
+    const PROTOCOL_FEE_BPS: u16 = 1000;
+
+    fn vault(total_staked: u64, total_shares: u64) -> Vault {
+        Vault {
+            fee_basis_points: 1000,
+            total_staked,
+            total_shares,
+            total_assets: total_staked,
+            high_water_mark: 1_000_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rewards_split_into_fee_shares() {
+        let mut v = vault(100 * UNIT, 100 * UNIT);
+
+        let d = v.distribute_rewards(110 * UNIT, PROTOCOL_FEE_BPS, 1).unwrap();
+
+        assert_eq!(d.rewards, 10 * UNIT);
+        assert_eq!(d.protocol_fee, UNIT);
+        assert_eq!(d.operator_fee, 900_000);
+        assert_eq!(d.staker_rewards, 10 * UNIT - 1_900_000);
+        // 1.9 units of fees at the pre-reward rate of 1.0
+        assert_eq!(d.protocol_shares + d.operator_shares, 1_900_000);
+        assert_eq!(d.protocol_shares, UNIT);
+        assert_eq!(v.total_staked, 110 * UNIT);
+        assert_eq!(v.total_assets, 110 * UNIT);
+        assert_eq!(v.total_shares, 100 * UNIT + 1_900_000);
+        assert_eq!(v.lifetime_rewards, 10 * UNIT);
+        assert_eq!(v.last_reward_epoch, 1);
+        assert_eq!(v.high_water_mark, v.exchange_rate().unwrap());
+    }
+
+    #[test]
+    fn loss_then_recovery_below_high_water_mark_is_not_charged() {
+        let mut v = vault(100 * UNIT, 100 * UNIT);
+
+        let loss = v.distribute_rewards(90 * UNIT, PROTOCOL_FEE_BPS, 1).unwrap();
+        assert_eq!(loss.loss, 10 * UNIT);
+        assert_eq!(v.total_assets, 90 * UNIT);
+        assert_eq!(v.high_water_mark, 1_000_000_000);
+        assert_eq!(v.high_water_mark_shortfall().unwrap(), 10 * UNIT);
+
+        // Partial recovery stays below the mark: no fees, no shares
+        let partial = v.distribute_rewards(95 * UNIT, PROTOCOL_FEE_BPS, 2).unwrap();
+        assert_eq!(partial.rewards, 5 * UNIT);
+        assert_eq!(partial.protocol_fee, 0);
+        assert_eq!(partial.operator_fee, 0);
+        assert_eq!(partial.staker_rewards, 5 * UNIT);
+        assert_eq!(v.total_shares, 100 * UNIT);
+        assert_eq!(v.high_water_mark, 1_000_000_000);
+        assert_eq!(v.high_water_mark_shortfall().unwrap(), 5 * UNIT);
+
+        // Only the 10 units above the mark are fee-eligible
+        let above = v.distribute_rewards(110 * UNIT, PROTOCOL_FEE_BPS, 3).unwrap();
+        assert_eq!(above.rewards, 15 * UNIT);
+        assert_eq!(above.protocol_fee, UNIT);
+        assert_eq!(above.operator_fee, 900_000);
+        // 1.9 units of fees at the pre-reward rate of 0.95
+        assert_eq!(above.protocol_shares + above.operator_shares, 2 * UNIT);
+        assert_eq!(v.total_shares, 102 * UNIT);
+        assert_eq!(v.high_water_mark_shortfall().unwrap(), 0);
+    }
+
+    #[test]
+    fn high_water_mark_shortfall_rounds_up() {
+        let mut v = vault(3, 3);
+        v.high_water_mark = 1_500_000_000;
+
+        // 1.5 * 3 = 4.5, rounded up to 5
+        assert_eq!(v.high_water_mark_shortfall().unwrap(), 2);
+
+        v.total_shares = 0;
+        assert_eq!(v.high_water_mark_shortfall().unwrap(), 0);
+    }
+
+    #[test]
+    fn rewards_book_once_per_epoch() {
+        let mut v = vault(100 * UNIT, 100 * UNIT);
+        v.distribute_rewards(101 * UNIT, PROTOCOL_FEE_BPS, 1).unwrap();
+
+        assert_eq!(
+            v.distribute_rewards(102 * UNIT, PROTOCOL_FEE_BPS, 1).unwrap_err(),
+            ErrorCode::RewardsAlreadyClaimed.into()
+        );
+    }
+
+    #[test]
+    fn unchanged_balance_only_advances_epoch() {
+        let mut v = vault(100 * UNIT, 100 * UNIT);
+
+        let d = v.distribute_rewards(100 * UNIT, PROTOCOL_FEE_BPS, 4).unwrap();
+
+        assert_eq!(d.rewards, 0);
+        assert_eq!(d.loss, 0);
+        assert_eq!(v.total_assets, 100 * UNIT);
+        assert_eq!(v.last_reward_epoch, 4);
+    }
+
+    #[test]
+    fn announced_fee_applies_with_its_epoch() {
+        let mut v = vault(100 * UNIT, 100 * UNIT);
+        v.pending_fee_basis_points = 2000;
+        v.pending_fee_epoch = 2;
+
+        let early = v.distribute_rewards(110 * UNIT, 0, 1).unwrap();
+        assert_eq!(early.operator_fee, UNIT);
+
+        let late = v.distribute_rewards(120 * UNIT, 0, 2).unwrap();
+        assert_eq!(late.operator_fee, 2 * UNIT);
+        assert_eq!(v.fee_basis_points, 2000);
+        assert!(!v.has_pending_fee());
+    }
+
+    #[test]
+    fn queued_withdrawals_leave_the_exchange_rate_unchanged() {
+        let mut v = vault(100 * UNIT, 100 * UNIT);
+
+        assert_eq!(v.queue_withdrawal(20 * UNIT).unwrap(), (0, 20 * UNIT));
+        assert_eq!(v.queue_withdrawal(10 * UNIT).unwrap(), (1, 10 * UNIT));
+
+        assert_eq!(v.total_shares, 70 * UNIT);
+        assert_eq!(v.total_assets, 70 * UNIT);
+        assert_eq!(v.pending_withdrawal_sol, 30 * UNIT);
+        assert_eq!(v.next_ticket_id, 2);
+        assert_eq!(v.exchange_rate().unwrap(), 1_000_000_000);
+    }
+
+    #[test]
+    fn reservation_is_fifo_when_buffer_is_short() {
+        let mut v = vault(70 * UNIT, 100 * UNIT);
+        v.buffered_sol = 30 * UNIT;
+        v.total_assets = 100 * UNIT;
+        v.queue_withdrawal(20 * UNIT).unwrap();
+        v.queue_withdrawal(20 * UNIT).unwrap();
+
+        // The head fits, the second ticket waits and the queue head stays put
+        assert!(v.reserve_for_ticket(20 * UNIT).unwrap());
+        assert!(!v.reserve_for_ticket(20 * UNIT).unwrap());
+        assert_eq!(v.reserved_sol, 20 * UNIT);
+        assert_eq!(v.pending_withdrawal_sol, 20 * UNIT);
+        assert_eq!(v.next_fulfill_ticket_id, 1);
+        assert_eq!(v.free_buffer(), 0);
+
+        // Withdrawn stake tops up the buffer and the next ticket goes through
+        v.buffered_sol += 15 * UNIT;
+        assert!(v.reserve_for_ticket(20 * UNIT).unwrap());
+        assert_eq!(v.reserved_sol, 40 * UNIT);
+        assert_eq!(v.pending_withdrawal_sol, 0);
+        assert_eq!(v.next_fulfill_ticket_id, 2);
+        assert_eq!(v.free_buffer(), 5 * UNIT);
+    }
+
+    #[test]
+    fn claims_release_reserved_buffer() {
+        let mut v = vault(0, 100 * UNIT);
+        v.buffered_sol = 100 * UNIT;
+        v.total_assets = 100 * UNIT;
+        v.queue_withdrawal(40 * UNIT).unwrap();
+        v.reserve_for_ticket(40 * UNIT).unwrap();
+
+        v.release_reserved(40 * UNIT).unwrap();
+        assert_eq!(v.buffered_sol, 60 * UNIT);
+        assert_eq!(v.reserved_sol, 0);
+
+        assert_eq!(
+            v.release_reserved(1).unwrap_err(),
+            ErrorCode::ArithmeticUnderflow.into()
+        );
+    }
+
+    #[test]
+    fn reserve_surplus_is_bounded_like_rewards() {
+        let v = vault(100 * UNIT, 100 * UNIT);
+
+        // 20% APR over one epoch caps rewards at ~0.11 units on 100
+        assert!(v.surplus_within_bounds(UNIT / 10, 1, 2000, 100).unwrap());
+        assert!(!v.surplus_within_bounds(UNIT, 1, 2000, 100).unwrap());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn reserve_surplus_without_shares_charges_no_fees() {
+        let mut v = vault(0, 0);
+
+        let d = v.book_reserve_surplus(5 * UNIT, PROTOCOL_FEE_BPS, 1).unwrap();
+
+        assert_eq!(d.protocol_fee, 0);
+        assert_eq!(d.operator_fee, 0);
+        assert_eq!(d.staker_rewards, 5 * UNIT);
+        assert_eq!(d.protocol_shares + d.operator_shares, 0);
+        assert_eq!(v.buffered_sol, 5 * UNIT);
+        assert_eq!(v.total_assets, 5 * UNIT);
+    }
+
+    #[test]
+    fn stake_withdrawal_books_against_total_staked() {
+        // Rewards booked by the oracle path already sit in total_staked
+        let mut v = vault(110 * UNIT, 100 * UNIT);
+
+        assert_eq!(v.book_stake_withdrawal(60 * UNIT).unwrap(), 60 * UNIT);
+        assert_eq!(v.total_staked, 50 * UNIT);
+        assert_eq!(v.buffered_sol, 60 * UNIT);
+        assert_eq!(v.total_assets, 110 * UNIT);
+
+        // Lamports beyond the booked stake are left for the reserve sync
+        assert_eq!(v.book_stake_withdrawal(55 * UNIT).unwrap(), 50 * UNIT);
+        assert_eq!(v.total_staked, 0);
+        assert_eq!(v.buffered_sol, 110 * UNIT);
+        assert_eq!(v.total_assets, 110 * UNIT);
+    }
+}
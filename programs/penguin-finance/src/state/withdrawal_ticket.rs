@@ -8,7 +8,10 @@ pub struct WithdrawalTicket {
     /// User who requested withdrawal
     pub user: Pubkey,
     
-    /// Unique ticket ID
+    /// Current holder of the claim, paid on claim (transferable)
+    pub owner: Pubkey,
+    
+    /// Sequential ticket ID within the vault, part of the PDA seeds
     pub ticket_id: u64,
     
     /// Amount of vault tokens burned
@@ -20,12 +23,9 @@ pub struct WithdrawalTicket {
     /// Epoch when withdrawal was requested
     pub request_epoch: u64,
     
-    /// Whether withdrawal is ready to claim
+    /// Whether buffer has been reserved for the ticket
     pub ready_to_claim: bool,
     
-    /// Whether ticket has been claimed
-    pub claimed: bool,
-    
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -34,11 +34,11 @@ impl WithdrawalTicket {
     pub const LEN: usize = 8 +  // discriminator
         32 + // vault
         32 + // user
+        32 + // owner
         8 +  // ticket_id
         8 +  // vault_tokens_burned
         8 +  // expected_sol_amount
         8 +  // request_epoch
         1 +  // ready_to_claim
-        1 +  // claimed
         1;   // bump
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+//! Helpers shared across instructions
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::stake::{
+    self,
+    state::{Delegation, StakeStateV2},
+};
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token_2022::{mint_to, MintTo, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::state::{RewardDistribution, Vault};
+
+/// Deserialize a stake account owned by the stake program
+pub fn read_stake_state(stake_account: &AccountInfo) -> Result<StakeStateV2> {
+    require_keys_eq!(
+        *stake_account.owner,
+        stake::program::ID,
+        ErrorCode::StakeAccountNotFound
+    );
+    let data = stake_account.try_borrow_data()?;
+    StakeStateV2::deserialize(&mut data.as_ref()).map_err(|_| error!(ErrorCode::InvalidStakeState))
+}
+
+/// Delegation of a delegated stake account
+pub fn read_stake_delegation(stake_account: &AccountInfo) -> Result<Delegation> {
+    read_stake_state(stake_account)?
+        .delegation()
+        .ok_or(error!(ErrorCode::InvalidStakeState))
+}
+
+/// Mint the fee shares of a reward distribution to the operator and treasury
+pub fn mint_fee_shares<'info>(
+    vault: &Account<'info, Vault>,
+    vault_token_mint: &InterfaceAccount<'info, Mint>,
+    operator_token_account: &InterfaceAccount<'info, TokenAccount>,
+    treasury_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Program<'info, Token2022>,
+    distribution: &RewardDistribution,
+) -> Result<()> {
+    let vault_seeds = &[
+        VAULT_SEED,
+        vault.factory.as_ref(),
+        &vault.vault_id.to_le_bytes(),
+        &[vault.bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    if distribution.operator_shares > 0 {
+        let mint_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: vault_token_mint.to_account_info(),
+                to: operator_token_account.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+        mint_to(mint_ctx, distribution.operator_shares)?;
+    }
+
+    if distribution.protocol_shares > 0 {
+        let mint_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: vault_token_mint.to_account_info(),
+                to: treasury_token_account.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+        mint_to(mint_ctx, distribution.protocol_shares)?;
+    }
+
+    Ok(())
+}
+
+/// Move SOL out of a vault's reserve PDA, which only the program can sign for
+pub fn transfer_from_reserve<'info>(
+    vault: &Account<'info, Vault>,
+    vault_reserve: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let vault_key = vault.key();
+    let reserve_seeds = &[
+        VAULT_RESERVE_SEED,
+        vault_key.as_ref(),
+        &[vault.reserve_bump],
+    ];
+
+    invoke_signed(
+        &system_instruction::transfer(vault_reserve.key, to.key, amount),
+        &[
+            vault_reserve.clone(),
+            to.clone(),
+            system_program.to_account_info(),
+        ],
+        &[&reserve_seeds[..]],
+    )?;
+
+    Ok(())
+}